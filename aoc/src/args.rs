@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use aoc_common::Part;
+
+pub const USAGE: &str = "\
+usage: aoc run [--day N | --day M-N] [--part 1|2] [--input PATH]
+       aoc dayN TOOL [--input PATH] [OPTIONS]
+       aoc help
+
+With no --day every day is run, and with no --part both parts are.
+
+The input comes from --input, or failing that the AOC_INPUT environment
+variable, or failing that dayN/input.txt.  \"{day}\" in the path is replaced
+by the day number, which is how to run several days against your own
+files.  A path of \"-\" reads stdin.
+
+Tools dig further into a day's input than the puzzle asks:
+  day1 report [--format table|json]    rank the elves and summarise them
+  day1 top [--n N]                     total calories of the N best-stocked elves
+  day2 score [--rules FILE]            score the guide under other rules (see
+                                       day2/rules for rock-paper-scissors-
+                                       lizard-Spock and RPS-7)
+  day2 analyze                         score every way of decoding X, Y and Z
+  day2 tournament [--rounds N]         pit strategies against each other (and
+                  [--players P,P,...]  the elves' guide): rock, paper,
+                                       scissors, random:SEED, frequency, wsls
+                                       and guide
+  day3 check [--strict]                list every bad rucksack and group,
+                                       then answer from the good ones (or,
+                                       with --strict, don't answer at all)
+  day4 coverage [--more-than K]        sections covered, left uncovered (in
+                [--range A-B]          the camp, or A-B) and assigned to
+                                       more than K elves (default 1)
+  day4 overlaps [--elf N]              how many pairs of elves overlap in the
+                                       whole camp, or which elves overlap
+                                       elf N (numbered from 1 in the order
+                                       they're listed)
+  day5 animate [--crane 9000|9001]     draw the stacks after every move,
+               [--delay MS]            waiting MS milliseconds (default
+                                       200) between drawings
+  day5 history [--crane 9000|9001]     when each bay first empties, or:
+               [--empty N] [--top C]   when bay N first empties, when crate
+               [--step N]              C first reaches the top of a bay, and
+                                       the top crates after N moves
+
+Day 1 tools count calories in an i32 unless --width says i64, u64 or u128.";
+
+// Where inputs are looked for when nobody says otherwise.
+pub const DEFAULT_INPUT: &str = "day{day}/input.txt";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Tool { day: u32, tool: String, args: Vec<String> },
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArgs {
+    pub days: Option<(u32, u32)>,
+    pub parts: Vec<Part>,
+    pub input: Option<String>,
+}
+
+// "5" is just day 5, "1-3" is days 1 through 3.
+fn parse_days(s: &str) -> Result<(u32, u32), String> {
+    let bad = || format!("not a day or range of days: {:?}", s);
+    let (first, last) = s.split_once('-').unwrap_or((s, s));
+    let first: u32 = first.parse().map_err(|_| bad())?;
+    let last: u32 = last.parse().map_err(|_| bad())?;
+    if first == 0 || first > last {
+        return Err(bad());
+    }
+    Ok((first, last))
+}
+
+// Picks the input path template from the flag, the environment and the
+// default, in that order.  A template without {day} names a single file, so
+// it can only be used for a single day.
+pub fn input_template(flag: Option<&str>, env: Option<&str>, single_day: bool) -> Result<String, String> {
+    let template = flag.or(env).unwrap_or(DEFAULT_INPUT);
+    if !single_day && !template.contains("{day}") {
+        return Err(format!("{} is a single file, but more than one day is being run", template));
+    }
+    Ok(template.to_string())
+}
+
+pub fn input_path(template: &str, day: u32) -> PathBuf {
+    PathBuf::from(template.replace("{day}", &day.to_string()))
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<RunArgs, String> {
+    let mut run = RunArgs {
+        days: None,
+        parts: Part::ALL.to_vec(),
+        input: None,
+    };
+
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", flag));
+        match flag.as_str() {
+            "--day" => run.days = Some(parse_days(&value()?)?),
+            "--part" => run.parts = vec![value()?.parse()?],
+            "--input" => run.input = Some(value()?),
+            _ => return Err(format!("unknown option: {}", flag)),
+        }
+    }
+    Ok(run)
+}
+
+// Takes the arguments without the program name.  A tool's own arguments
+// are left for the tool to make sense of.
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => Ok(Command::Run(parse_run(args)?)),
+        Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+        Some(other) => match other.strip_prefix("day").map(str::parse) {
+            Some(Ok(day)) => {
+                let tool = args.next().ok_or(format!("{} needs a tool", other))?;
+                Ok(Command::Tool { day, tool, args: args.collect() })
+            }
+            _ => Err(format!("unknown command: {}", other)),
+        },
+    }
+}
+
+// The --name value options and --name switches a tool was given.  Tools
+// say which names they take of each; anything else is an error.
+#[derive(Debug)]
+pub struct Flags {
+    values: HashMap<String, String>,
+    switches: Vec<String>,
+}
+
+impl Flags {
+    pub fn parse(args: &[String], valued: &[&str], switches: &[&str]) -> Result<Flags, String> {
+        let mut values = HashMap::new();
+        let mut on = Vec::new();
+        let mut args = args.iter();
+
+        while let Some(flag) = args.next() {
+            let name = flag
+                .strip_prefix("--")
+                .ok_or(format!("unexpected argument: {}", flag))?;
+            if valued.contains(&name) {
+                let value = args.next().ok_or(format!("{} needs a value", flag))?;
+                values.insert(name.to_string(), value.clone());
+            } else if switches.contains(&name) {
+                on.push(name.to_string());
+            } else {
+                return Err(format!("unknown option: {}", flag));
+            }
+        }
+        Ok(Flags { values, switches: on })
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    pub fn switch(&self, name: &str) -> bool {
+        self.switches.iter().any(|s| s == name)
+    }
+
+    // The value of --name parsed as a T, or the default if it wasn't given.
+    pub fn parsed<T>(&self, name: &str, default: T) -> Result<T, String>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        match self.value(name) {
+            Some(v) => v.parse().map_err(|e| format!("--{}: {}", name, e)),
+            None => Ok(default),
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[cfg(test)]
+fn parse(s: &str) -> Result<Command, String> {
+    parse_args(s.split_whitespace().map(String::from))
+}
+
+#[test]
+fn test_parse_run() {
+    assert_eq!(
+        parse("run --day 5 --part 2 --input foo.txt"),
+        Ok(Command::Run(RunArgs {
+            days: Some((5, 5)),
+            parts: vec![Part::Two],
+            input: Some("foo.txt".to_string()),
+        }))
+    );
+    assert_eq!(
+        parse("run --day 1-3"),
+        Ok(Command::Run(RunArgs {
+            days: Some((1, 3)),
+            parts: vec![Part::One, Part::Two],
+            input: None,
+        }))
+    );
+    assert_eq!(parse(""), Ok(Command::Help));
+}
+
+#[test]
+fn test_parse_run_errors() {
+    assert!(parse("run --day").is_err());
+    assert!(parse("run --day 3-1").is_err());
+    assert!(parse("run --day 0").is_err());
+    assert!(parse("run --part 3").is_err());
+    assert!(parse("walk").is_err());
+}
+
+#[test]
+fn test_input_template() {
+    assert_eq!(input_template(None, None, false), Ok(DEFAULT_INPUT.to_string()));
+    assert_eq!(input_template(None, Some("env.txt"), true), Ok("env.txt".to_string()));
+    assert_eq!(input_template(Some("-"), Some("env.txt"), true), Ok("-".to_string()));
+    assert!(input_template(Some("-"), None, false).is_err());
+    assert!(input_template(None, Some("env.txt"), false).is_err());
+
+    assert_eq!(input_path("examples/day{day}.txt", 4), PathBuf::from("examples/day4.txt"));
+}
+
+#[test]
+fn test_parse_tool() {
+    assert_eq!(
+        parse("day1 report --format json"),
+        Ok(Command::Tool {
+            day: 1,
+            tool: "report".to_string(),
+            args: vec!["--format".to_string(), "json".to_string()],
+        })
+    );
+    assert!(parse("day1").is_err());
+    assert!(parse("dayone report").is_err());
+}
+
+#[test]
+fn test_flags() {
+    let args: Vec<String> = "--format json".split(' ').map(String::from).collect();
+    let flags = Flags::parse(&args, &["format", "delay"], &["strict"]).unwrap();
+    assert_eq!(flags.value("format"), Some("json"));
+    assert_eq!(flags.parsed("delay", 5u32), Ok(5));
+    assert!(!flags.switch("strict"));
+
+    let bad: Vec<String> = vec!["--delay".to_string(), "soon".to_string()];
+    let flags = Flags::parse(&bad, &["delay"], &[]).unwrap();
+    assert!(flags.parsed("delay", 5u32).is_err());
+
+    assert!(Flags::parse(&args, &["delay"], &[]).is_err());
+    assert!(Flags::parse(&["json".to_string()], &["format"], &[]).is_err());
+    assert!(Flags::parse(&["--format".to_string()], &["format"], &[]).is_err());
+
+    let strict: Vec<String> = vec!["--strict".to_string(), "--format".to_string(), "json".to_string()];
+    let flags = Flags::parse(&strict, &["format"], &["strict"]).unwrap();
+    assert!(flags.switch("strict"));
+    assert_eq!(flags.value("format"), Some("json"));
+    assert!(Flags::parse(&strict, &["format"], &[]).is_err());
+}
@@ -0,0 +1,77 @@
+use std::env;
+use std::process;
+
+use aoc_common::{solve, Answer, AocError, Input, Part};
+
+mod args;
+mod tools;
+use args::{input_path, input_template, parse_args, Command, RunArgs, USAGE};
+
+type Solver = fn(Input, &[Part]) -> Result<Vec<Answer>, AocError>;
+
+// Day N is DAYS[N - 1].
+const DAYS: [Solver; 6] = [
+    solve::<day1::Day1>,
+    solve::<day2::Day2>,
+    solve::<day3::Day3>,
+    solve::<day4::Day4>,
+    solve::<day5::Day5>,
+    solve::<day6::Day6>,
+];
+
+// Prints each part's answer, or says why there isn't one, and gives back
+// how many parts had no answer.  Only an input that won't open or parse
+// is an error for the whole day.
+fn run_day(day: u32, template: &str, run: &RunArgs) -> Result<usize, AocError> {
+    let input = Input::open(input_path(template, day))?;
+    let name = input.name();
+    let answers = DAYS[day as usize - 1](input, &run.parts).map_err(|e| e.in_file(&name))?;
+    let mut failed = 0;
+    for (part, answer) in answers {
+        match answer {
+            Ok(answer) => println!("Day {} Part {}: {}", day, part, answer),
+            Err(e) => {
+                eprintln!("aoc: day {} part {}: {}", day, part, e.in_file(&name));
+                failed += 1;
+            }
+        }
+    }
+    Ok(failed)
+}
+
+fn run(run: RunArgs) -> Result<(), String> {
+    let (first, last) = run.days.unwrap_or((1, DAYS.len() as u32));
+    if last as usize > DAYS.len() {
+        return Err(format!("only days 1-{} are solved so far", DAYS.len()));
+    }
+
+    let env = env::var("AOC_INPUT").ok();
+    let template = input_template(run.input.as_deref(), env.as_deref(), first == last)?;
+
+    let mut failed = 0;
+    for day in first..=last {
+        failed += run_day(day, &template, &run).map_err(|e| format!("day {}: {}", day, e))?;
+    }
+    match failed {
+        0 => Ok(()),
+        1 => Err("1 part had no answer".to_string()),
+        n => Err(format!("{} parts had no answer", n)),
+    }
+}
+
+fn main() {
+    let result = match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::Tool { day, tool, args }) => tools::run_tool(day, &tool, &args),
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Err(e) => Err(format!("{}\n\n{}", e, USAGE)),
+    };
+
+    if let Err(e) = result {
+        eprintln!("aoc: {}", e);
+        process::exit(1);
+    }
+}
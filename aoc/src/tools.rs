@@ -0,0 +1,315 @@
+use std::env;
+
+use aoc_common::{AocError, Input};
+
+use crate::args::{input_path, input_template, Flags};
+
+// Opens the day's input the same way `aoc run` would.
+fn open_input(day: u32, flags: &Flags) -> Result<Input, String> {
+    let env = env::var("AOC_INPUT").ok();
+    let template = input_template(flags.value("input"), env.as_deref(), true)?;
+    Input::open(input_path(&template, day)).map_err(|e| e.to_string())
+}
+
+// Runs f on the day's input, blaming the input file for anything it can't
+// parse.
+fn with_input<T, F>(day: u32, flags: &Flags, f: F) -> Result<T, String>
+where
+    F: FnOnce(Input) -> Result<T, AocError>,
+{
+    let input = open_input(day, flags)?;
+    let name = input.name();
+    f(input).map_err(|e| e.in_file(name).to_string())
+}
+
+fn day1_report<T: day1::Calories>(flags: &Flags) -> Result<(), String> {
+    let format: day1::report::Format = flags.parsed("format", day1::report::Format::Table)?;
+
+    let report = with_input(1, flags, |input| {
+        let elves = day1::inventories(input.into_reader()).collect::<Result<_, _>>()?;
+        day1::report::Report::<T>::new(elves)
+    })?;
+    println!("{}", report.render(format));
+    Ok(())
+}
+
+fn day1_top<T: day1::Calories>(flags: &Flags) -> Result<(), String> {
+    let n: usize = flags.parsed("n", 3)?;
+
+    let sum = with_input(1, flags, |input| {
+        let top: Vec<T> = day1::top_n(day1::elf_totals(input.into_reader()), n)?;
+        day1::sum_of_top(&top, n)
+    })?;
+    println!("{}", sum);
+    Ok(())
+}
+
+// Runs a day 1 tool with calories counted in whatever --width asks for.
+macro_rules! by_width {
+    ($tool:ident, $flags:expr) => {
+        match $flags.parsed("width", day1::Width::I32)? {
+            day1::Width::I32 => $tool::<i32>($flags),
+            day1::Width::I64 => $tool::<i64>($flags),
+            day1::Width::U64 => $tool::<u64>($flags),
+            day1::Width::U128 => $tool::<u128>($flags),
+        }
+    };
+}
+
+fn day2_score(args: &[String]) -> Result<(), String> {
+    let flags = Flags::parse(args, &["input", "rules"], &[])?;
+    let rules = match flags.value("rules") {
+        Some(path) => {
+            let file = Input::open(path).map_err(|e| e.to_string())?;
+            let name = file.name();
+            let text = file.string().map_err(|e| e.to_string())?;
+            day2::rules::Rules::parse(&text).map_err(|e| e.in_file(name).to_string())?
+        }
+        None => day2::rules::Rules::classic(),
+    };
+
+    let score = with_input(2, &flags, |input| rules.score_guide(&input.lines()?))?;
+    println!("{}", score);
+    Ok(())
+}
+
+fn day2_analyze(args: &[String]) -> Result<(), String> {
+    use aoc_common::Solution;
+
+    let flags = Flags::parse(args, &["input"], &[])?;
+    let guide = with_input(2, &flags, day2::Day2::parse)?;
+    if guide.is_empty() {
+        return Err("the strategy guide is empty".to_string());
+    }
+    println!("{}", day2::analyze::Analysis::new(&guide));
+    Ok(())
+}
+
+fn day2_tournament(args: &[String]) -> Result<(), String> {
+    use aoc_common::Solution;
+    use day2::tournament::{Player, Tournament};
+
+    let flags = Flags::parse(args, &["input", "rounds", "players"], &[])?;
+    let rounds: usize = flags.parsed("rounds", 1000)?;
+    let players: Vec<Player> = flags
+        .value("players")
+        .unwrap_or("rock,random:2022,frequency,wsls,guide")
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+
+    // Only the guide player needs the guide, so only insist on it then.
+    let guide: Vec<day2::Shape> = if players.contains(&Player::Guide) {
+        with_input(2, &flags, day2::Day2::parse)?.iter().map(|(theirs, _)| *theirs).collect()
+    } else {
+        Vec::new()
+    };
+
+    println!("{}", Tournament::run(players, &guide, rounds)?);
+    Ok(())
+}
+
+fn day3_check(args: &[String]) -> Result<(), String> {
+    let flags = Flags::parse(args, &["input"], &["strict"])?;
+    let validation = with_input(3, &flags, |input| Ok(day3::validate::Validation::new(&input.lines()?)))?;
+
+    let problems = validation.problems();
+    for problem in &problems {
+        eprintln!("{}", problem);
+    }
+    if flags.switch("strict") && !problems.is_empty() {
+        return Err(format!("{} problems in {} lines, so no answer", problems.len(), validation.lines.len()));
+    }
+    println!("Day 3 Part 1: {}", validation.part1());
+    println!("Day 3 Part 2: {}", validation.part2());
+    Ok(())
+}
+
+fn day4_coverage(args: &[String]) -> Result<(), String> {
+    use aoc_common::Solution;
+    use day4::coverage::Coverage;
+    use day4::interval::Interval;
+
+    let flags = Flags::parse(args, &["input", "more-than", "range"], &[])?;
+    let k: usize = flags.parsed("more-than", 1)?;
+    let assignments: Vec<Interval<u32>> = with_input(4, &flags, day4::Day4::parse)?
+        .into_iter()
+        .flat_map(|g| g.ranges)
+        .collect();
+    let coverage = Coverage::new(&assignments);
+    let covered = coverage.covered();
+
+    // Gaps are looked for across the whole camp unless --range says where.
+    let camp = match covered.intervals() {
+        [first, .., last] => Interval::new(first.start(), last.end()),
+        [only] => Some(*only),
+        [] => None,
+    };
+    let range = match flags.value("range") {
+        Some(range) => Some(range.parse().map_err(|e| format!("--range: {}", e))?),
+        None => camp,
+    };
+    let listed = |set: day4::coverage::IntervalSet<u32>| {
+        if set.is_empty() { "none".to_string() } else { set.to_string() }
+    };
+
+    println!("sections covered: {}", covered.len());
+    if let Some(range) = range {
+        println!("covered by nobody in {}: {}", range, listed(covered.gaps(&range)));
+    }
+    if let Some((run, elves)) = coverage.busiest() {
+        println!("most assigned: {} ({} elves)", run, elves);
+    }
+    println!("assigned to more than {} elves: {}", k, listed(coverage.more_than(k)));
+    Ok(())
+}
+
+fn day4_overlaps(args: &[String]) -> Result<(), String> {
+    use aoc_common::Solution;
+    use day4::index::OverlapIndex;
+
+    let flags = Flags::parse(args, &["input", "elf"], &[])?;
+    let elf: Option<usize> = flags.value("elf").map(|_| flags.parsed("elf", 0)).transpose()?;
+    // Elves are numbered from 1 in the order they're listed.
+    let assignments: Vec<day4::interval::Interval<u32>> = with_input(4, &flags, day4::Day4::parse)?
+        .into_iter()
+        .flat_map(|g| g.ranges)
+        .collect();
+    let index = OverlapIndex::new(&assignments);
+
+    match elf {
+        Some(elf) if elf == 0 || elf > index.len() => {
+            Err(format!("there is no elf {} (there are {})", elf, index.len()))
+        }
+        Some(elf) => {
+            let others: Vec<String> = index.overlapping_with(elf - 1).iter().map(|n| (n + 1).to_string()).collect();
+            println!("elf {} ({}) overlaps {} others: {}", elf, assignments[elf - 1], others.len(), others.join(" "));
+            Ok(())
+        }
+        None => {
+            println!("{}", index.overlapping_pairs());
+            Ok(())
+        }
+    }
+}
+
+fn day5_history<C: day5::crane::Crane>(flags: &Flags, crane: C) -> Result<(), String> {
+    use aoc_common::Solution;
+    use day5::journal::Journal;
+
+    let (start, moves) = with_input(5, flags, day5::Day5::parse)?;
+    let bays = start.bay_count();
+    let journal = Journal::replay(start, &moves, crane).map_err(|e| e.to_string())?;
+    // Step 0 is the start; step n is after move n.
+    let when = |step: usize| match step {
+        0 => "from the start".to_string(),
+        n => format!("after move {} ({})", n, moves[n - 1]),
+    };
+
+    if let Some(step) = flags.value("step").map(|_| flags.parsed("step", 0)).transpose()? {
+        let sy = journal
+            .state_at(step)
+            .ok_or_else(|| format!("there is no step {} (there are {} moves)", step, moves.len()))?;
+        let tops: String = (1..=bays).map(|bay| sy.peek_bay(bay).copied().unwrap_or(' ')).collect();
+        println!("{}, {}: {}", journal.crane().name(), when(step), tops);
+    }
+    if let Some(top) = flags.value("top") {
+        let mut chars = top.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return Err(format!("--top: {:?} is not a single crate", top));
+        };
+        match journal.first_on_top(&c) {
+            Some((step, bay)) => println!("[{}] is first on top of bay {} {}", c, bay, when(step)),
+            None => println!("[{}] is never on top", c),
+        }
+    }
+    // Unless asked about something else, say when each bay first empties.
+    let empty: Vec<usize> = match flags.value("empty") {
+        Some(_) => vec![flags.parsed("empty", 0)?],
+        None if flags.value("step").is_none() && flags.value("top").is_none() => (1..=bays).collect(),
+        None => Vec::new(),
+    };
+    if let Some(bay) = empty.iter().find(|&&bay| bay == 0 || bay > bays) {
+        return Err(format!("there is no bay {} (there are {})", bay, bays));
+    }
+    // One trip through the moves finds them all.
+    let mut first_empty: Vec<Option<usize>> = vec![None; bays];
+    journal.walk(|step, sy| {
+        for &bay in &empty {
+            if first_empty[bay - 1].is_none() && sy.bay(bay).is_some_and(|b| b.is_empty()) {
+                first_empty[bay - 1] = Some(step);
+            }
+        }
+        empty.iter().all(|&bay| first_empty[bay - 1].is_some())
+    });
+    for bay in empty {
+        match first_empty[bay - 1] {
+            Some(step) => println!("bay {} is first empty {}", bay, when(step)),
+            None => println!("bay {} is never empty", bay),
+        }
+    }
+    Ok(())
+}
+
+// Draws the shipyard after every move, waiting --delay milliseconds
+// between them.  On a terminal each drawing replaces the last; anywhere
+// else they're just listed one after another.
+fn day5_animate<C: day5::crane::Crane>(flags: &Flags, crane: C) -> Result<(), String> {
+    use std::io::IsTerminal;
+    use std::time::Duration;
+
+    use aoc_common::Solution;
+    use day5::journal::Journal;
+
+    let delay = Duration::from_millis(flags.parsed("delay", 200)?);
+    let (start, moves) = with_input(5, flags, day5::Day5::parse)?;
+    let journal = Journal::replay(start, &moves, crane).map_err(|e| e.to_string())?;
+    let terminal = std::io::stdout().is_terminal();
+
+    journal.walk(|step, sy| {
+        if terminal {
+            // Clear the screen and go back to the top.
+            print!("\x1b[2J\x1b[H");
+        } else if step > 0 {
+            println!();
+        }
+        match step {
+            0 => println!("{}, at the start", journal.crane().name()),
+            n => println!("{}, move {} of {}: {}", journal.crane().name(), n, moves.len(), moves[n - 1]),
+        }
+        println!("{}", sy);
+        if step < moves.len() {
+            std::thread::sleep(delay);
+        }
+        false
+    });
+    Ok(())
+}
+
+// Runs a day 5 tool with the crane --crane names: 9000 (the default) or
+// 9001.
+macro_rules! by_crane {
+    ($tool:ident, $flags:expr) => {
+        match $flags.value("crane") {
+            None | Some("9000") => $tool($flags, day5::crane::CrateMover9000),
+            Some("9001") => $tool($flags, day5::crane::CrateMover9001),
+            Some(other) => Err(format!("--crane: there is no CrateMover {}", other)),
+        }
+    };
+}
+
+pub fn run_tool(day: u32, tool: &str, args: &[String]) -> Result<(), String> {
+    match (day, tool) {
+        (1, "report") => by_width!(day1_report, &Flags::parse(args, &["input", "format", "width"], &[])?),
+        (1, "top") => by_width!(day1_top, &Flags::parse(args, &["input", "n", "width"], &[])?),
+        (2, "score") => day2_score(args),
+        (2, "analyze") => day2_analyze(args),
+        (2, "tournament") => day2_tournament(args),
+        (3, "check") => day3_check(args),
+        (4, "coverage") => day4_coverage(args),
+        (4, "overlaps") => day4_overlaps(args),
+        (5, "animate") => by_crane!(day5_animate, &Flags::parse(args, &["input", "crane", "delay"], &[])?),
+        (5, "history") => by_crane!(day5_history, &Flags::parse(args, &["input", "crane", "step", "empty", "top"], &[])?),
+        _ => Err(format!("day {} has no {} tool", day, tool)),
+    }
+}
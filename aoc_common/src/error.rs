@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// Everything that can go wrong between opening an input and printing an
+// answer.  Parse errors carry enough to find the offending line again.
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    Parse {
+        file: Option<PathBuf>,
+        line: usize,
+        text: String,
+        reason: String,
+    },
+    // The input made sense, but there's nothing in it that answers the
+    // question (no elves to rank, no marker in the signal, ...).
+    NoAnswer(String),
+    // The input made sense, but asks for something that can't be done.
+    Invalid(String),
+}
+
+impl AocError {
+    // line is 1-based, as an editor would show it.
+    pub fn parse<R: Display>(line: usize, text: &str, reason: R) -> AocError {
+        AocError::Parse {
+            file: None,
+            line,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    // Says which file a parse error came from, unless it already knows.
+    pub fn in_file<P: AsRef<Path>>(self, path: P) -> AocError {
+        match self {
+            AocError::Parse { file: None, line, text, reason } => AocError::Parse {
+                file: Some(path.as_ref().to_path_buf()),
+                line,
+                text,
+                reason,
+            },
+            other => other,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "{}", e),
+            AocError::Parse { file, line, text, reason } => {
+                if let Some(file) = file {
+                    write!(f, "{}: ", file.display())?;
+                }
+                write!(f, "line {}: {}: {:?}", line, reason, text)
+            }
+            AocError::NoAnswer(why) => write!(f, "no answer: {}", why),
+            AocError::Invalid(why) => write!(f, "{}", why),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> AocError {
+        AocError::Io(e)
+    }
+}
+
+// Parses one line of input, blaming that line if it won't go.
+pub fn parse_line<T>(line: usize, text: &str) -> Result<T, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse().map_err(|e| AocError::parse(line, text, e))
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_parse_line() {
+    assert_eq!(parse_line::<u32>(1, "42").unwrap(), 42);
+
+    let err = parse_line::<u32>(7, "4x2").unwrap_err();
+    assert_eq!(err.to_string(), "line 7: invalid digit found in string: \"4x2\"");
+    assert_eq!(
+        err.in_file("day1/input.txt").to_string(),
+        "day1/input.txt: line 7: invalid digit found in string: \"4x2\""
+    );
+}
+
+#[test]
+fn test_in_file_keeps_first_file() {
+    let err = AocError::parse(3, "x", "bad").in_file("start.txt").in_file("input.txt");
+    assert!(err.to_string().starts_with("start.txt: line 3"));
+
+    let io = AocError::from(io::Error::new(io::ErrorKind::NotFound, "gone")).in_file("input.txt");
+    assert_eq!(io.to_string(), "gone");
+}
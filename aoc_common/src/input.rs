@@ -0,0 +1,170 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufRead, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{parse_line, AocError};
+use crate::slices::split_on;
+
+// The path that means "read standard input instead".
+pub const STDIN: &str = "-";
+
+// A puzzle input on its way into a Solution.  It remembers where it came
+// from, for messages.
+pub struct Input {
+    path: PathBuf,
+    reader: Box<dyn BufRead>,
+}
+
+impl Input {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Input, io::Error> {
+        let path = path.as_ref();
+        let reader: Box<dyn BufRead> = if path == Path::new(STDIN) {
+            Box::new(io::stdin().lock())
+        } else {
+            let file: File = File::open(path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+            Box::new(io::BufReader::new(file))
+        };
+        Ok(Input {
+            path: path.to_path_buf(),
+            reader,
+        })
+    }
+
+    pub fn from_reader<R: BufRead + 'static>(name: &str, reader: R) -> Input {
+        Input {
+            path: PathBuf::from(name),
+            reader: Box::new(reader),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // What to call this input in messages.
+    pub fn name(&self) -> String {
+        if self.path == Path::new(STDIN) {
+            "<stdin>".to_string()
+        } else {
+            self.path.display().to_string()
+        }
+    }
+
+    pub fn reader(&mut self) -> &mut dyn BufRead {
+        &mut self.reader
+    }
+
+    // For days that would rather stream the input than hold it all at once.
+    pub fn into_reader(self) -> Box<dyn BufRead> {
+        self.reader
+    }
+
+    pub fn lines(self) -> Result<Vec<String>, io::Error> {
+        lines(self.reader)
+    }
+
+    pub fn groups(self) -> Result<Vec<Vec<String>>, io::Error> {
+        Ok(groups(&self.lines()?))
+    }
+
+    pub fn string(mut self) -> Result<String, io::Error> {
+        let mut s = String::new();
+        self.reader.read_to_string(&mut s)?;
+        Ok(s)
+    }
+
+    pub fn chars(self) -> Result<Vec<char>, io::Error> {
+        Ok(self.string()?.trim_end().chars().collect())
+    }
+
+    pub fn parsed<T>(self) -> Result<Vec<T>, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        parse_lines(&self.lines()?)
+    }
+}
+
+// Every reader here works on any BufRead, and the read_* versions are just
+// the same thing pointed at a file (or at stdin, given "-").
+
+pub fn lines<R: BufRead>(reader: R) -> Result<Vec<String>, io::Error> {
+    reader.lines().collect()
+}
+
+pub fn readlines(filename: &str) -> Result<Vec<String>, io::Error> {
+    Input::open(filename)?.lines()
+}
+
+// Splits lines into the blank-line-separated groups the puzzles like to use.
+// Runs of blank lines don't produce empty groups.
+pub fn groups(lines: &[String]) -> Vec<Vec<String>> {
+    split_on(lines, |s| s.is_empty()).map(<[String]>::to_vec).collect()
+}
+
+pub fn read_groups(filename: &str) -> Result<Vec<Vec<String>>, io::Error> {
+    Ok(groups(&readlines(filename)?))
+}
+
+pub fn read_string(filename: &str) -> Result<String, io::Error> {
+    Input::open(filename)?.string()
+}
+
+// The whole file as chars, minus the trailing newline.
+pub fn read_chars(filename: &str) -> Result<Vec<char>, io::Error> {
+    Input::open(filename)?.chars()
+}
+
+// Parses every line into a T.  A line that won't parse is reported with its
+// (1-based) line number rather than panicking.
+pub fn read_parsed<T>(filename: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    parse_lines(&readlines(filename)?)
+}
+
+fn parse_lines<T>(lines: &[String]) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| parse_line(i + 1, l))
+        .collect()
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_lines() {
+    let read = lines("a\nb\n\nc".as_bytes()).unwrap();
+    assert_eq!(read, vec!["a", "b", "", "c"]);
+}
+
+#[test]
+fn test_groups() {
+    let read = lines("1\n2\n\n3\n\n\n4\n".as_bytes()).unwrap();
+    assert_eq!(
+        groups(&read),
+        vec![vec!["1", "2"], vec!["3"], vec!["4"]]
+    );
+}
+
+#[test]
+fn test_parse_lines() {
+    let good: Vec<i32> = parse_lines(&["1".to_string(), "-2".to_string()]).unwrap();
+    assert_eq!(good, vec![1, -2]);
+
+    let bad = parse_lines::<i32>(&["1".to_string(), "x".to_string()]).unwrap_err();
+    assert!(matches!(bad, AocError::Parse { line: 2, .. }));
+}
@@ -0,0 +1,12 @@
+// Shared helpers for the daily puzzles.  Anything that more than one day
+// needs (loading input, for a start) lives here so it only gets fixed once.
+
+pub mod error;
+pub mod input;
+pub mod slices;
+pub mod solution;
+
+pub use error::{parse_line, AocError};
+pub use input::{groups, lines, read_chars, read_groups, read_parsed, read_string, readlines, Input};
+pub use slices::{chunks, exact_chunks, split_on, windows, Ragged};
+pub use solution::{solve, Answer, Part, Solution};
@@ -0,0 +1,169 @@
+use std::fmt;
+
+// Borrowing ways of cutting a slice into pieces: fixed-size chunks, chunks
+// that have to come out exactly, sliding windows and separator-delimited
+// groups.  Every piece is a subslice of what was passed in, so nothing is
+// copied and nothing allocated.
+//
+// Like the standard library's, sizes have to be at least 1.
+
+pub struct Chunks<'a, T> {
+    rest: &'a [T],
+    size: usize,
+}
+
+// Pieces of size items, except perhaps for a shorter one at the end.
+pub fn chunks<T>(items: &[T], size: usize) -> Chunks<'_, T> {
+    assert!(size > 0, "chunks need a size of at least 1");
+    Chunks { rest: items, size }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.rest.split_at(self.size.min(self.rest.len()));
+        self.rest = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.rest.len().div_ceil(self.size);
+        (n, Some(n))
+    }
+}
+
+// What's left over when items won't split into chunks of the same size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ragged {
+    pub len: usize,
+    pub size: usize,
+}
+
+impl Ragged {
+    pub fn left_over(&self) -> usize {
+        self.len % self.size
+    }
+
+    // Where the short chunk would have started.
+    pub fn start(&self) -> usize {
+        self.len - self.left_over()
+    }
+}
+
+impl fmt::Display for Ragged {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} won't split into groups of {}, with {} left over",
+            self.len,
+            self.size,
+            self.left_over()
+        )
+    }
+}
+
+// Pieces of exactly size items, or an error if the last would be short.
+pub fn exact_chunks<T>(items: &[T], size: usize) -> Result<Chunks<'_, T>, Ragged> {
+    let chunks = chunks(items, size);
+    if !items.len().is_multiple_of(size) {
+        return Err(Ragged { len: items.len(), size });
+    }
+    Ok(chunks)
+}
+
+pub struct Windows<'a, T> {
+    items: &'a [T],
+    size: usize,
+    start: usize,
+}
+
+// Every run of size items in a row, overlapping, in order.  Shorter input
+// has none.
+pub fn windows<T>(items: &[T], size: usize) -> Windows<'_, T> {
+    assert!(size > 0, "windows need a size of at least 1");
+    Windows { items, size, start: 0 }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        let window = self.items.get(self.start..self.start + self.size)?;
+        self.start += 1;
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = (self.items.len() + 1).saturating_sub(self.start + self.size);
+        (n, Some(n))
+    }
+}
+
+pub struct SplitOn<'a, T, P> {
+    rest: &'a [T],
+    is_separator: P,
+}
+
+// The groups between separators, leaving the separators out.  Runs of
+// separators (and separators at either end) don't produce empty groups,
+// which is how the puzzles' blank-line-separated input wants reading.
+pub fn split_on<T, P: FnMut(&T) -> bool>(items: &[T], is_separator: P) -> SplitOn<'_, T, P> {
+    SplitOn { rest: items, is_separator }
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> Iterator for SplitOn<'a, T, P> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        let start = self.rest.iter().position(|x| !(self.is_separator)(x))?;
+        let rest = &self.rest[start..];
+        let end = rest.iter().position(|x| (self.is_separator)(x)).unwrap_or(rest.len());
+        let (group, rest) = rest.split_at(end);
+        self.rest = rest;
+        Some(group)
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_chunks() {
+    let items = [1, 2, 3, 4, 5, 6, 7];
+    let cut: Vec<&[i32]> = chunks(&items, 3).collect();
+    assert_eq!(cut, vec![&[1, 2, 3][..], &[4, 5, 6], &[7]]);
+    assert_eq!(chunks(&items, 3).size_hint(), (3, Some(3)));
+    assert_eq!(chunks(&items[..0], 3).count(), 0);
+
+    let exact: Vec<&[i32]> = exact_chunks(&items[..6], 2).unwrap().collect();
+    assert_eq!(exact, vec![&[1, 2][..], &[3, 4], &[5, 6]]);
+
+    let ragged = exact_chunks(&items, 3).err().unwrap();
+    assert_eq!((ragged.left_over(), ragged.start()), (1, 6));
+    assert_eq!(ragged.to_string(), "7 won't split into groups of 3, with 1 left over");
+}
+
+#[test]
+fn test_windows() {
+    let items = ['a', 'b', 'c', 'd'];
+    let seen: Vec<String> = windows(&items, 3).map(|w| w.iter().collect()).collect();
+    assert_eq!(seen, vec!["abc", "bcd"]);
+    assert_eq!(windows(&items, 3).size_hint(), (2, Some(2)));
+    assert_eq!(windows(&items, 4).count(), 1);
+    assert_eq!(windows(&items, 5).count(), 0);
+    assert!(windows(&items, 1).eq(items.chunks(1)));
+}
+
+#[test]
+fn test_split_on() {
+    let items = [0, 1, 2, 0, 3, 0, 0, 4, 0];
+    let groups: Vec<&[i32]> = split_on(&items, |&x| x == 0).collect();
+    assert_eq!(groups, vec![&[1, 2][..], &[3], &[4]]);
+    assert_eq!(split_on(&items[..1], |&x| x == 0).count(), 0);
+    assert_eq!(split_on(&items[1..3], |&x| x == 0).count(), 1);
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::AocError;
+use crate::input::Input;
+
+// Each day implements this and the runner takes care of the rest.  parse is
+// handed the puzzle input once, and both parts work off whatever it built.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: Input) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<String, AocError>;
+    fn part2(input: &Self::Input) -> Result<String, AocError>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Part, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("no such part: {:?}", s)),
+        }
+    }
+}
+
+// A part, and its answer or why there isn't one.
+pub type Answer = (Part, Result<String, AocError>);
+
+// Parses the input once and answers each of the requested parts, in order.
+// Only a bad parse fails the lot: a part that can't be answered doesn't stop
+// the others.
+pub fn solve<S: Solution>(input: Input, parts: &[Part]) -> Result<Vec<Answer>, AocError> {
+    let parsed = S::parse(input)?;
+    Ok(parts
+        .iter()
+        .map(|&part| match part {
+            Part::One => (part, S::part1(&parsed)),
+            Part::Two => (part, S::part2(&parsed)),
+        })
+        .collect())
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[cfg(test)]
+struct Doubler;
+
+#[cfg(test)]
+impl Solution for Doubler {
+    type Input = Vec<i32>;
+
+    fn parse(input: Input) -> Result<Vec<i32>, AocError> {
+        input.parsed()
+    }
+
+    fn part1(input: &Vec<i32>) -> Result<String, AocError> {
+        Ok(input.iter().sum::<i32>().to_string())
+    }
+
+    fn part2(input: &Vec<i32>) -> Result<String, AocError> {
+        if input.is_empty() {
+            return Err(AocError::NoAnswer("nothing to double".to_string()));
+        }
+        Ok((input.iter().sum::<i32>() * 2).to_string())
+    }
+}
+
+// The answers with any errors as strings, which can be compared.
+#[cfg(test)]
+fn answered(answers: Vec<Answer>) -> Vec<(Part, Result<String, String>)> {
+    answers.into_iter().map(|(part, a)| (part, a.map_err(|e| e.to_string()))).collect()
+}
+
+#[test]
+fn test_solve() {
+    let input = Input::from_reader("test", "1\n2\n3".as_bytes());
+    let answers = solve::<Doubler>(input, &[Part::Two, Part::One]).unwrap();
+    assert_eq!(answered(answers), vec![(Part::Two, Ok("12".to_string())), (Part::One, Ok("6".to_string()))]);
+}
+
+#[test]
+fn test_solve_errors() {
+    let input = Input::from_reader("test", "1\nzero".as_bytes());
+    let err = solve::<Doubler>(input, &Part::ALL).unwrap_err();
+    assert!(matches!(err, AocError::Parse { line: 2, .. }));
+
+    let input = Input::from_reader("test", "".as_bytes());
+    assert_eq!(answered(solve::<Doubler>(input, &[Part::One]).unwrap()), vec![(Part::One, Ok("0".to_string()))]);
+
+    // Part 2 has no answer, but part 1 still does.
+    let input = Input::from_reader("test", "".as_bytes());
+    let answers = solve::<Doubler>(input, &Part::ALL).unwrap();
+    assert!(matches!(answers[1], (Part::Two, Err(AocError::NoAnswer(_)))));
+    assert_eq!(
+        answered(answers),
+        vec![(Part::One, Ok("0".to_string())), (Part::Two, Err("no answer: nothing to double".to_string()))]
+    );
+}
+
+#[test]
+fn test_part_from_str() {
+    assert_eq!("1".parse::<Part>(), Ok(Part::One));
+    assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+    assert!("3".parse::<Part>().is_err());
+}
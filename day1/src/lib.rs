@@ -0,0 +1,279 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{Debug, Display};
+use std::io::{self, BufRead};
+use std::marker::PhantomData;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use aoc_common::{parse_line, AocError, Input, Solution};
+
+pub mod report;
+
+// Whatever integer type calories are counted in.  The puzzle fits in an
+// i32, but generated inputs don't have to, and sums are always checked so
+// that running out of room is an error rather than a wrong answer.
+pub trait Calories: Copy + Ord + Debug + Display + FromStr<Err = ParseIntError> {
+    const ZERO: Self;
+    const NAME: &'static str;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_calories {
+    ($($t:ty),*) => {
+        $(
+            impl Calories for $t {
+                const ZERO: $t = 0;
+                const NAME: &'static str = stringify!($t);
+
+                fn checked_add(self, other: $t) -> Option<$t> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_calories!(i32, i64, u64, u128);
+
+// The Calories types, for picking one at runtime.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Width {
+    I32,
+    I64,
+    U64,
+    U128,
+}
+
+impl FromStr for Width {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Width, String> {
+        match s {
+            "i32" => Ok(Width::I32),
+            "i64" => Ok(Width::I64),
+            "u64" => Ok(Width::U64),
+            "u128" => Ok(Width::U128),
+            _ => Err(format!("no such width: {:?} (try i32, i64, u64 or u128)", s)),
+        }
+    }
+}
+
+// One elf's worth of input.  Elves are numbered from 1, in the order they
+// turn up.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElfInventory<T = i32> {
+    pub index: usize,
+    pub items: Vec<T>,
+    pub total: T,
+}
+
+// Reads elf inventories one at a time.  Inventories are separated by blank
+// lines, and only the current one is ever held, so the input can be as big
+// as it likes.
+pub struct Inventories<R, T = i32> {
+    lines: io::Lines<R>,
+    line: usize,
+    elves: usize,
+    calories: PhantomData<T>,
+}
+
+pub fn inventories<R: BufRead, T: Calories>(reader: R) -> Inventories<R, T> {
+    Inventories {
+        lines: reader.lines(),
+        line: 0,
+        elves: 0,
+        calories: PhantomData,
+    }
+}
+
+impl<R: BufRead, T: Calories> Inventories<R, T> {
+    fn next_inventory(&mut self) -> Result<Option<ElfInventory<T>>, AocError> {
+        let mut items: Vec<T> = Vec::new();
+        let mut total: T = T::ZERO;
+
+        for line in self.lines.by_ref() {
+            let line = line?;
+            self.line += 1;
+            if line.is_empty() {
+                if !items.is_empty() {
+                    break;
+                }
+            } else {
+                let item: T = parse_line(self.line, &line)?;
+                total = total.checked_add(item).ok_or_else(|| {
+                    AocError::Invalid(format!(
+                        "line {}: elf {} is carrying more calories than {} can hold",
+                        self.line,
+                        self.elves + 1,
+                        T::NAME
+                    ))
+                })?;
+                items.push(item);
+            }
+        }
+
+        if items.is_empty() {
+            return Ok(None);
+        }
+        self.elves += 1;
+        Ok(Some(ElfInventory {
+            index: self.elves,
+            items,
+            total,
+        }))
+    }
+}
+
+impl<R: BufRead, T: Calories> Iterator for Inventories<R, T> {
+    type Item = Result<ElfInventory<T>, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_inventory().transpose()
+    }
+}
+
+// Just the total calories each elf carries.
+pub fn elf_totals<R: BufRead, T: Calories>(reader: R) -> impl Iterator<Item = Result<T, AocError>> {
+    inventories(reader).map(|elf| elf.map(|e: ElfInventory<T>| e.total))
+}
+
+// Keeps the n biggest values it has been shown, and nothing else.  It
+// only ever holds as many as it's been shown, however big n is.
+pub struct TopN<T> {
+    n: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopN<T> {
+    pub fn new(n: usize) -> TopN<T> {
+        TopN {
+            n,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.heap.push(Reverse(value));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+
+    // Biggest first.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap.into_sorted_vec().into_iter().map(|Reverse(v)| v).collect()
+    }
+}
+
+// The n biggest totals, biggest first, in a single pass.
+pub fn top_n<I, T>(totals: I, n: usize) -> Result<Vec<T>, AocError>
+where
+    I: IntoIterator<Item = Result<T, AocError>>,
+    T: Ord,
+{
+    let mut top = TopN::new(n);
+    for total in totals {
+        top.push(total?);
+    }
+    Ok(top.into_sorted_vec())
+}
+
+// Answers wanting fewer than all n elves just use the front of the list.
+pub fn sum_of_top<T: Calories>(top: &[T], n: usize) -> Result<T, AocError> {
+    if top.len() < n {
+        return Err(AocError::NoAnswer(format!("only {} elves, need {}", top.len(), n)));
+    }
+    top[..n].iter().try_fold(T::ZERO, |sum, &total| sum.checked_add(total)).ok_or_else(|| {
+        AocError::Invalid(format!("the top {} elves carry more calories than {} can hold", n, T::NAME))
+    })
+}
+
+pub struct Day1<T = i32>(PhantomData<T>);
+
+impl<T: Calories> Solution for Day1<T> {
+    // The three biggest totals, biggest first, which covers both parts.
+    type Input = Vec<T>;
+
+    fn parse(input: Input) -> Result<Vec<T>, AocError> {
+        top_n(elf_totals(input.into_reader()), 3)
+    }
+
+    fn part1(top: &Vec<T>) -> Result<String, AocError> {
+        Ok(format!("{:?}", sum_of_top(top, 1)?))
+    }
+
+    fn part2(top: &Vec<T>) -> Result<String, AocError> {
+        Ok(format!("{:?}", sum_of_top(top, 3)?))
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_elf_totals() {
+    let totals: Vec<i32> = elf_totals("1\n2\n\n\n3\n\n4\n".as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(totals, vec![3, 3, 4]);
+
+    let bad: Result<Vec<i32>, AocError> = elf_totals("1\n\ntwo".as_bytes()).collect();
+    assert!(matches!(bad, Err(AocError::Parse { line: 3, .. })));
+}
+
+#[test]
+fn test_inventories() {
+    let elves: Vec<ElfInventory> = inventories("\n1\n2\n\n3\n".as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        elves,
+        vec![
+            ElfInventory { index: 1, items: vec![1, 2], total: 3 },
+            ElfInventory { index: 2, items: vec![3], total: 3 },
+        ]
+    );
+}
+
+#[test]
+fn test_top_n() {
+    let example = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+    assert_eq!(top_n(elf_totals::<_, i32>(example.as_bytes()), 3).unwrap(), vec![24000, 11000, 10000]);
+    assert_eq!(top_n(elf_totals::<_, u64>(example.as_bytes()), 1).unwrap(), vec![24000]);
+    assert_eq!(top_n(elf_totals::<_, i32>("5\n\n6".as_bytes()), 3).unwrap(), vec![6, 5]);
+
+    // Asking for far more elves than there are costs nothing extra.
+    assert_eq!(top_n(elf_totals::<_, i32>(example.as_bytes()), usize::MAX).unwrap().len(), 5);
+    assert_eq!(top_n(elf_totals::<_, i32>("5\n\n6".as_bytes()), 100_000_000_000).unwrap(), vec![6, 5]);
+    assert_eq!(top_n(elf_totals::<_, i32>("5\n\n6".as_bytes()), 0).unwrap(), Vec::<i32>::new());
+}
+
+#[test]
+fn test_overflow() {
+    let big = "2000000000\n\n1\n\n2000000000\n2000000000\n";
+    let totals: Result<Vec<i32>, AocError> = elf_totals(big.as_bytes()).collect();
+    match totals {
+        Err(AocError::Invalid(why)) => assert!(why.starts_with("line 6: elf 3 ")),
+        other => panic!("expected an overflow, got {:?}", other),
+    }
+
+    let totals: Vec<i64> = elf_totals(big.as_bytes()).collect::<Result<_, _>>().unwrap();
+    assert_eq!(totals, vec![2_000_000_000, 1, 4_000_000_000]);
+
+    // Each elf fits, but the top three together don't.
+    let top: Vec<u64> = vec![u64::MAX - 1, 1, 1];
+    assert_eq!(sum_of_top(&top, 2).unwrap(), u64::MAX);
+    assert!(matches!(sum_of_top(&top, 3), Err(AocError::Invalid(_))));
+
+    let huge = "340282366920938463463374607431768211455\n";
+    assert!(elf_totals::<_, u64>(huge.as_bytes()).next().unwrap().is_err());
+    assert_eq!(elf_totals::<_, u128>(huge.as_bytes()).next().unwrap().unwrap(), u128::MAX);
+}
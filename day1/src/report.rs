@@ -0,0 +1,148 @@
+use std::cmp::Reverse;
+use std::fmt::Write;
+
+use aoc_common::AocError;
+
+use crate::{Calories, ElfInventory};
+
+// A closer look at the elves than the puzzle needs: who carries what, how
+// they rank, and how the totals are spread.
+pub struct Report<T = i32> {
+    // Biggest total first.  Elves with the same total keep their input order.
+    pub ranking: Vec<ElfInventory<T>>,
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("no such format: {:?} (try table or json)", s)),
+        }
+    }
+}
+
+impl<T: Calories> Report<T> {
+    pub fn new(mut elves: Vec<ElfInventory<T>>) -> Result<Report<T>, AocError> {
+        if elves.is_empty() {
+            return Err(AocError::NoAnswer("there are no elves".to_string()));
+        }
+
+        let n = elves.len() as f64;
+        let mean = elves.iter().map(|e| e.total.as_f64()).sum::<f64>() / n;
+        let variance = elves
+            .iter()
+            .map(|e| (e.total.as_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        elves.sort_by_key(|e| Reverse(e.total));
+
+        let middle = elves.len() / 2;
+        let median = if elves.len() % 2 == 1 {
+            elves[middle].total.as_f64()
+        } else {
+            (elves[middle - 1].total.as_f64() + elves[middle].total.as_f64()) / 2.0
+        };
+
+        Ok(Report {
+            ranking: elves,
+            mean,
+            median,
+            stddev: variance.sqrt(),
+        })
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Table => self.table(),
+            Format::Json => self.json(),
+        }
+    }
+
+    fn table(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "{:>6} {:>6} {:>6} {:>10}", "rank", "elf", "items", "total").unwrap();
+        for (rank, elf) in self.ranking.iter().enumerate() {
+            writeln!(out, "{:>6} {:>6} {:>6} {:>10}", rank + 1, elf.index, elf.items.len(), elf.total).unwrap();
+        }
+        writeln!(out).unwrap();
+        writeln!(out, "elves:  {}", self.ranking.len()).unwrap();
+        writeln!(out, "items:  {}", self.ranking.iter().map(|e| e.items.len()).sum::<usize>()).unwrap();
+        writeln!(out, "mean:   {:.2}", self.mean).unwrap();
+        writeln!(out, "median: {:.2}", self.median).unwrap();
+        write!(out, "stddev: {:.2}", self.stddev).unwrap();
+        out
+    }
+
+    fn json(&self) -> String {
+        let elves: Vec<String> = self
+            .ranking
+            .iter()
+            .enumerate()
+            .map(|(rank, elf)| {
+                let items: Vec<String> = elf.items.iter().map(|i| i.to_string()).collect();
+                format!(
+                    "{{\"rank\":{},\"index\":{},\"items\":[{}],\"total\":{}}}",
+                    rank + 1,
+                    elf.index,
+                    items.join(","),
+                    elf.total
+                )
+            })
+            .collect();
+        format!(
+            "{{\"elves\":[{}],\"mean\":{},\"median\":{},\"stddev\":{}}}",
+            elves.join(","),
+            self.mean,
+            self.median,
+            self.stddev
+        )
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[cfg(test)]
+fn example() -> Vec<ElfInventory> {
+    let example = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+    crate::inventories(example.as_bytes()).collect::<Result<_, _>>().unwrap()
+}
+
+#[test]
+fn test_report_stats() {
+    let report = Report::new(example()).unwrap();
+
+    let ranked: Vec<usize> = report.ranking.iter().map(|e| e.index).collect();
+    assert_eq!(ranked, vec![4, 3, 5, 1, 2]);
+    assert_eq!(report.mean, 11000.0);
+    assert_eq!(report.median, 10000.0);
+    assert_eq!(report.stddev, 48_800_000.0_f64.sqrt());
+
+    assert!(Report::<i32>::new(vec![]).is_err());
+}
+
+#[test]
+fn test_report_json() {
+    let elves = example().into_iter().take(2).collect();
+    let report = Report::new(elves).unwrap();
+    assert_eq!(
+        report.render(Format::Json),
+        "{\"elves\":[{\"rank\":1,\"index\":1,\"items\":[1000,2000,3000],\"total\":6000},\
+         {\"rank\":2,\"index\":2,\"items\":[4000],\"total\":4000}],\
+         \"mean\":5000,\"median\":5000,\"stddev\":1000}"
+    );
+}
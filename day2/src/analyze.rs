@@ -0,0 +1,137 @@
+use std::fmt;
+
+use crate::{correct_with, total_score, Code, GuideLine, Outcome, Round, Shape};
+
+// One way of decoding the second column of the guide.  Each array is
+// indexed by the code (X, Y, Z).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Reading {
+    Shapes([Shape; 3]),
+    Outcomes([Outcome; 3]),
+}
+
+impl Reading {
+    // The readings the two parts of the puzzle use.
+    pub const PART1: Reading = Reading::Shapes([Shape::Rock, Shape::Paper, Shape::Scissors]);
+    pub const PART2: Reading = Reading::Outcomes([Outcome::Lose, Outcome::Draw, Outcome::Win]);
+
+    pub fn round(&self, (theirs, code): GuideLine) -> Round {
+        match self {
+            Reading::Shapes(shapes) => (theirs, shapes[code.index()]),
+            Reading::Outcomes(outcomes) => correct_with((theirs, code), |c| outcomes[c.index()]),
+        }
+    }
+
+    pub fn score(&self, guide: &[GuideLine]) -> i32 {
+        guide.iter().map(|line| total_score(self.round(*line))).sum()
+    }
+}
+
+impl fmt::Display for Reading {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let meanings: Vec<String> = match self {
+            Reading::Shapes(shapes) => shapes.iter().map(|s| format!("{:?}", s)).collect(),
+            Reading::Outcomes(outcomes) => outcomes.iter().map(|o| format!("{:?}", o)).collect(),
+        };
+        let pairs: Vec<String> = Code::ALL
+            .iter()
+            .zip(meanings)
+            .map(|(code, meaning)| format!("{:?}={}", code, meaning))
+            .collect();
+        write!(f, "{}", pairs.join(" "))
+    }
+}
+
+// Every ordering of three things.
+fn permutations<T: Copy>([a, b, c]: [T; 3]) -> [[T; 3]; 6] {
+    [[a, b, c], [a, c, b], [b, a, c], [b, c, a], [c, a, b], [c, b, a]]
+}
+
+// Every way of reading the guide: the six ways of mapping X, Y and Z to
+// shapes, then the six ways of mapping them to outcomes.
+pub fn readings() -> Vec<Reading> {
+    let shapes = permutations(Shape::ALL).into_iter().map(Reading::Shapes);
+    let outcomes = permutations([Outcome::Lose, Outcome::Draw, Outcome::Win])
+        .into_iter()
+        .map(Reading::Outcomes);
+    shapes.chain(outcomes).collect()
+}
+
+// How the guide scores under every reading, in the order readings() gives
+// them.
+pub struct Analysis {
+    pub scores: Vec<(Reading, i32)>,
+}
+
+impl Analysis {
+    pub fn new(guide: &[GuideLine]) -> Analysis {
+        Analysis {
+            scores: readings().into_iter().map(|r| (r, r.score(guide))).collect(),
+        }
+    }
+
+    // Ties go to whichever reading comes first.
+    pub fn best(&self) -> (Reading, i32) {
+        self.scores.iter().fold(self.scores[0], |best, &s| if s.1 > best.1 { s } else { best })
+    }
+
+    pub fn worst(&self) -> (Reading, i32) {
+        self.scores.iter().fold(self.scores[0], |worst, &s| if s.1 < worst.1 { s } else { worst })
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (reading, score) in &self.scores {
+            let note = match *reading {
+                Reading::PART1 => "  (part 1)",
+                Reading::PART2 => "  (part 2)",
+                _ => "",
+            };
+            writeln!(f, "{:<32} {:>8}{}", reading.to_string(), score, note)?;
+        }
+        let (best, best_score) = self.best();
+        let (worst, worst_score) = self.worst();
+        writeln!(f)?;
+        writeln!(f, "best:  {} ({})", best, best_score)?;
+        write!(f, "worst: {} ({})", worst, worst_score)
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[cfg(test)]
+fn example() -> Vec<GuideLine> {
+    vec![(Shape::Rock, Code::Y), (Shape::Paper, Code::X), (Shape::Scissors, Code::Z)]
+}
+
+#[test]
+fn test_readings_match_the_puzzle() {
+    let guide = example();
+    assert_eq!(Reading::PART1.score(&guide), 15);
+    assert_eq!(Reading::PART2.score(&guide), 12);
+
+    for line in guide {
+        assert_eq!(Reading::PART2.round(line), crate::correct(line));
+    }
+}
+
+#[test]
+fn test_analysis() {
+    let analysis = Analysis::new(&example());
+    assert_eq!(analysis.scores.len(), 12);
+    assert!(analysis.scores.contains(&(Reading::PART1, 15)));
+
+    // Winning every round: paper beats rock, scissors beat paper and rock
+    // beats scissors, so 2+6 + 3+6 + 1+6.
+    let (best, score) = analysis.best();
+    assert_eq!(best, Reading::Shapes([Shape::Scissors, Shape::Paper, Shape::Rock]));
+    assert_eq!(score, 24);
+
+    // And losing every one: 3+0 + 1+0 + 2+0.
+    let (worst, score) = analysis.worst();
+    assert_eq!(worst, Reading::Shapes([Shape::Rock, Shape::Scissors, Shape::Paper]));
+    assert_eq!(score, 6);
+}
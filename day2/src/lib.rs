@@ -0,0 +1,296 @@
+use std::str::FromStr;
+use aoc_common::{AocError, Input, Solution};
+
+pub mod analyze;
+pub mod rules;
+pub mod tournament;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Outcome {
+    Win,
+    Lose,
+    Draw
+}
+
+// The second column of the strategy guide.  What it means depends on which
+// part of the puzzle we're doing.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Code {
+    X,
+    Y,
+    Z
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    // The shape this one beats.
+    pub fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper
+        }
+    }
+
+    // The shape that beats this one.
+    pub fn loses_to(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock
+        }
+    }
+
+    // How it goes for this shape when thrown against other.
+    pub fn against(self, other: Shape) -> Outcome {
+        if self.beats() == other {
+            Outcome::Win
+        } else if self.loses_to() == other {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    // What to throw against other to get the outcome.
+    pub fn to_get(outcome: Outcome, other: Shape) -> Shape {
+        match outcome {
+            Outcome::Win => other.loses_to(),
+            Outcome::Lose => other.beats(),
+            Outcome::Draw => other
+        }
+    }
+
+    pub fn score(self) -> i32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3
+        }
+    }
+}
+
+impl Outcome {
+    pub fn score(self) -> i32 {
+        match self {
+            Outcome::Win => 6,
+            Outcome::Draw => 3,
+            Outcome::Lose => 0
+        }
+    }
+}
+
+impl Code {
+    pub const ALL: [Code; 3] = [Code::X, Code::Y, Code::Z];
+
+    pub fn index(self) -> usize {
+        match self {
+            Code::X => 0,
+            Code::Y => 1,
+            Code::Z => 2
+        }
+    }
+
+    // Part 1 reads X, Y and Z as the shape to throw...
+    pub fn shape(self) -> Shape {
+        match self {
+            Code::X => Shape::Rock,
+            Code::Y => Shape::Paper,
+            Code::Z => Shape::Scissors
+        }
+    }
+
+    // ...and part 2 as how the round should end.
+    pub fn outcome(self) -> Outcome {
+        match self {
+            Code::X => Outcome::Lose,
+            Code::Y => Outcome::Draw,
+            Code::Z => Outcome::Win
+        }
+    }
+}
+
+// Their column: A, B or C.  Ours is a Code, which only becomes a shape
+// once a part decides how to read it.
+impl FromStr for Shape {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Shape, String> {
+        match s {
+            "A" => Ok(Shape::Rock),
+            "B" => Ok(Shape::Paper),
+            "C" => Ok(Shape::Scissors),
+            _ => Err(format!("expected A, B or C, found {:?}", s))
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Outcome, String> {
+        Ok(s.parse::<Code>()?.outcome())
+    }
+}
+
+impl FromStr for Code {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Code, String> {
+        match s {
+            "X" => Ok(Code::X),
+            "Y" => Ok(Code::Y),
+            "Z" => Ok(Code::Z),
+            _ => Err(format!("expected X, Y or Z, found {:?}", s))
+        }
+    }
+}
+
+// A line of the strategy guide: what they throw, and the code for our side.
+pub type GuideLine = (Shape, Code);
+
+// A round as it's actually played: what they throw, and what we throw.
+pub type Round = (Shape, Shape);
+
+fn line_to_tuple(number: usize, line: &str) -> Result<GuideLine, AocError> {
+    let bad = |e: String| AocError::parse(number, line, e);
+    let (theirs, ours) = line.split_once(' ').ok_or_else(|| bad("expected two letters".to_string()))?;
+    Ok((theirs.parse().map_err(bad)?, ours.parse().map_err(bad)?))
+}
+
+fn lines_to_tuples(lines: Vec<String>) -> Result<Vec<GuideLine>, AocError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| line_to_tuple(i + 1, l))
+        .collect()
+}
+
+// How the round went for us.
+pub fn game_result((theirs, ours): Round) -> Outcome {
+    ours.against(theirs)
+}
+
+pub fn total_score(round: Round) -> i32 {
+    round.1.score() + game_result(round).score()
+}
+
+// Part 1 reads the guide as telling us what to throw.
+pub fn as_thrown((theirs, code): GuideLine) -> Round {
+    (theirs, code.shape())
+}
+
+// Part 2 reads it as telling us how the round should end, so this works
+// out what we have to throw to make that happen.
+pub fn correct(line: GuideLine) -> Round {
+    correct_with(line, Code::outcome)
+}
+
+// The same, for any idea of which code means which outcome.
+pub fn correct_with<F: Fn(Code) -> Outcome>((theirs, code): GuideLine, outcome: F) -> Round {
+    (theirs, Shape::to_get(outcome(code), theirs))
+}
+
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<GuideLine>;
+
+    fn parse(input: Input) -> Result<Self::Input, AocError> {
+        lines_to_tuples(input.lines()?)
+    }
+
+    fn part1(guide: &Self::Input) -> Result<String, AocError> {
+        let scores: Vec<i32> = guide
+            .iter()
+            .map(|line| total_score(as_thrown(*line)))
+            .collect();
+
+        Ok(format!("{:?}", scores.iter().sum::<i32>()))
+    }
+
+    fn part2(guide: &Self::Input) -> Result<String, AocError> {
+        let corrected_scores: Vec<i32> = guide
+            .iter()
+            .map(|line| total_score(correct(*line)))
+            .collect();
+
+        Ok(format!("{:?}", corrected_scores.iter().sum::<i32>()))
+    }
+}
+
+// -----------------------------------------------------------------------------------------------
+// TESTS
+// -----------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+use Shape::{Paper, Rock, Scissors};
+
+#[test]
+fn test_win_logic() {
+    // paper beats rock
+    assert_eq!(game_result((Rock, Paper)), Outcome::Win);
+    
+    // rock beats scissors
+    assert_eq!(game_result((Rock, Scissors)), Outcome::Lose);
+
+    // scissors beat paper
+    assert_eq!(game_result((Paper, Scissors)), Outcome::Win);
+
+    // paper beats rock
+    assert_eq!(game_result((Paper, Rock)), Outcome::Lose);
+
+    // scissors beat paper
+    assert_eq!(game_result((Scissors, Paper)), Outcome::Lose);
+
+    // rock beats scissors
+    assert_eq!(game_result((Scissors, Rock)), Outcome::Win);
+
+    // same is a draw
+    assert_eq!(game_result((Rock, Rock)), Outcome::Draw);
+    assert_eq!(game_result((Paper, Paper)), Outcome::Draw);
+    assert_eq!(game_result((Scissors, Scissors)), Outcome::Draw);
+
+
+}
+
+#[test]
+fn test_correct() {
+    // Whatever they throw, the corrected round ends the way the code says.
+    for theirs in Shape::ALL {
+        for code in Code::ALL {
+            let round = correct((theirs, code));
+            assert_eq!(round.0, theirs);
+            assert_eq!(game_result(round), code.outcome());
+        }
+    }
+
+    // The worked example from the puzzle.
+    assert_eq!(total_score(correct((Rock, Code::Y))), 4);
+    assert_eq!(total_score(correct((Paper, Code::X))), 1);
+    assert_eq!(total_score(correct((Scissors, Code::Z))), 7);
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(line_to_tuple(1, "B Z").unwrap(), (Paper, Code::Z));
+    assert!("X".parse::<Shape>().is_err());
+    assert_eq!("C".parse::<Shape>(), Ok(Scissors));
+    assert_eq!("Z".parse::<Outcome>(), Ok(Outcome::Win));
+
+    let lines = vec!["A Y".to_string(), "D X".to_string()];
+    assert!(matches!(lines_to_tuples(lines), Err(AocError::Parse { line: 2, .. })));
+
+    assert!(line_to_tuple(1, "A").is_err());
+    assert!(line_to_tuple(1, "X X").is_err());
+    assert!(line_to_tuple(1, "A X Y").is_err());
+}
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use aoc_common::AocError;
+
+use crate::Outcome;
+
+// Rock-paper-scissors with any odd number of shapes.  The shapes sit in a
+// circle and each one beats the (N-1)/2 shapes after it, which makes every
+// pair of different shapes a decisive round and no shape better than any
+// other.  Shapes are referred to by their position in the circle.
+//
+// A rules file lists the shapes in circle order, one per line, as
+//
+//     shape NAME SCORE THEIR-LETTER OUR-LETTER
+//
+// and can change what outcomes are worth with
+//
+//     points win|draw|lose N
+//
+// Blank lines and lines starting with # are ignored.
+#[derive(Clone, Debug)]
+pub struct Rules {
+    names: Vec<String>,
+    scores: Vec<i32>,
+    theirs: HashMap<String, usize>,
+    ours: HashMap<String, usize>,
+    points: HashMap<Outcome, i32>,
+}
+
+// Their shape, then ours.
+pub type Round = (usize, usize);
+
+impl Rules {
+    // The game from the puzzle.  Rock beats scissors beats paper beats rock.
+    pub fn classic() -> Rules {
+        Rules::parse(
+            "shape Rock 1 A X\n\
+             shape Scissors 3 C Z\n\
+             shape Paper 2 B Y\n",
+        )
+        .unwrap()
+    }
+
+    pub fn parse(text: &str) -> Result<Rules, AocError> {
+        let mut rules = Rules {
+            names: Vec::new(),
+            scores: Vec::new(),
+            theirs: HashMap::new(),
+            ours: HashMap::new(),
+            points: HashMap::from([(Outcome::Win, 6), (Outcome::Draw, 3), (Outcome::Lose, 0)]),
+        };
+
+        for (i, line) in text.lines().enumerate() {
+            let bad = |why: &str| AocError::parse(i + 1, line, why);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                [] => {}
+                [first, ..] if first.starts_with('#') => {}
+                ["shape", name, score, theirs, ours] => {
+                    let shape = rules.names.len();
+                    if rules.names.iter().any(|n| n == name) {
+                        return Err(bad("there is already a shape with that name"));
+                    }
+                    let score = score.parse().map_err(|_| bad("the score is not a number"))?;
+                    if rules.theirs.insert(theirs.to_string(), shape).is_some() {
+                        return Err(bad("their letter is already taken"));
+                    }
+                    if rules.ours.insert(ours.to_string(), shape).is_some() {
+                        return Err(bad("our letter is already taken"));
+                    }
+                    rules.names.push(name.to_string());
+                    rules.scores.push(score);
+                }
+                ["points", outcome, points] => {
+                    let outcome = match *outcome {
+                        "win" => Outcome::Win,
+                        "draw" => Outcome::Draw,
+                        "lose" => Outcome::Lose,
+                        _ => return Err(bad("expected win, draw or lose")),
+                    };
+                    let points = points.parse().map_err(|_| bad("the points are not a number"))?;
+                    rules.points.insert(outcome, points);
+                }
+                _ => return Err(bad("expected \"shape NAME SCORE THEIRS OURS\" or \"points OUTCOME N\"")),
+            }
+        }
+
+        if rules.names.len().is_multiple_of(2) {
+            return Err(AocError::Invalid(format!(
+                "a fair game needs an odd number of shapes, not {}",
+                rules.names.len()
+            )));
+        }
+        Ok(rules)
+    }
+
+    pub fn shape_count(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn name(&self, shape: usize) -> &str {
+        &self.names[shape]
+    }
+
+    // Does shape beat other?
+    pub fn beats(&self, shape: usize, other: usize) -> bool {
+        let n = self.shape_count();
+        let distance = (other + n - shape) % n;
+        distance >= 1 && distance <= (n - 1) / 2
+    }
+
+    // How it goes for shape when thrown against other.
+    pub fn outcome(&self, shape: usize, other: usize) -> Outcome {
+        if shape == other {
+            Outcome::Draw
+        } else if self.beats(shape, other) {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    pub fn total_score(&self, (theirs, ours): Round) -> i32 {
+        self.scores[ours] + self.points[&self.outcome(ours, theirs)]
+    }
+
+    // Reads a strategy guide line, taking our letter as the shape to throw.
+    pub fn parse_round(&self, number: usize, line: &str) -> Result<Round, AocError> {
+        let bad = |why: String| AocError::parse(number, line, why);
+        let (theirs, ours) = line
+            .split_once(' ')
+            .ok_or_else(|| bad("expected two letters".to_string()))?;
+        let theirs = self.theirs.get(theirs).ok_or_else(|| bad(format!("they have no {:?}", theirs)))?;
+        let ours = self.ours.get(ours).ok_or_else(|| bad(format!("we have no {:?}", ours)))?;
+        Ok((*theirs, *ours))
+    }
+
+    // The score for following a whole strategy guide.
+    pub fn score_guide(&self, lines: &[String]) -> Result<i32, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, l)| Ok(self.total_score(self.parse_round(i + 1, l)?)))
+            .sum()
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[cfg(test)]
+const RPSLS: &str = include_str!("../rules/rpsls.txt");
+
+#[cfg(test)]
+const RPS7: &str = include_str!("../rules/rps7.txt");
+
+#[test]
+fn test_classic_matches_day2() {
+    use crate::{as_thrown, line_to_tuple, total_score};
+
+    let rules = Rules::classic();
+    for theirs in ["A", "B", "C"] {
+        for ours in ["X", "Y", "Z"] {
+            let line = format!("{} {}", theirs, ours);
+            let expected = total_score(as_thrown(line_to_tuple(1, &line).unwrap()));
+            assert_eq!(rules.total_score(rules.parse_round(1, &line).unwrap()), expected);
+        }
+    }
+}
+
+#[test]
+fn test_every_shape_is_as_good_as_any_other() {
+    for text in [RPSLS, RPS7] {
+        let rules = Rules::parse(text).unwrap();
+        let n = rules.shape_count();
+        for shape in 0..n {
+            let wins = (0..n).filter(|&other| rules.beats(shape, other)).count();
+            assert_eq!(wins, (n - 1) / 2);
+            for other in 0..n {
+                assert!(!(rules.beats(shape, other) && rules.beats(other, shape)));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rpsls() {
+    let rules = Rules::parse(RPSLS).unwrap();
+    let shape = |name: &str| (0..rules.shape_count()).find(|&s| rules.name(s) == name).unwrap();
+
+    assert!(rules.beats(shape("Spock"), shape("Scissors")));
+    assert!(rules.beats(shape("Lizard"), shape("Spock")));
+    assert!(rules.beats(shape("Paper"), shape("Spock")));
+    assert!(!rules.beats(shape("Rock"), shape("Paper")));
+
+    let guide = vec!["A Y".to_string(), "D X".to_string(), "E W".to_string()];
+    // Paper covers rock, Rock crushes lizard, and Spock draws with Spock.
+    assert_eq!(rules.score_guide(&guide).unwrap(), (2 + 6) + (1 + 6) + (5 + 3));
+}
+
+#[test]
+fn test_bad_rules() {
+    assert!(Rules::parse("shape Rock 1 A X\nshape Paper 2 B Y\n").is_err());
+    assert!(matches!(
+        Rules::parse("shape Rock 1 A X\nshape Paper 2 A Y\n"),
+        Err(AocError::Parse { line: 2, .. })
+    ));
+    assert!(Rules::parse("shape Rock one A X\n").is_err());
+    assert!(Rules::parse("points tie 3\n").is_err());
+}
@@ -0,0 +1,361 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{game_result, total_score, Outcome, Shape};
+
+// A way of playing.  Each round the strategy throws, then gets to see what
+// the other side threw and how it went.
+pub trait Strategy {
+    fn throw(&mut self) -> Shape;
+    fn observe(&mut self, _ours: Shape, _theirs: Shape, _outcome: Outcome) {}
+}
+
+// A tiny xorshift generator.  It's nowhere near good enough for anything
+// that matters, but it is reproducible from a seed, which is what we want.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // A splitmix64 step scatters neighbouring seeds.  Zero is a fixed
+        // point of xorshift, and exactly one seed scatters to it, so that
+        // one gets moved.
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        match z ^ (z >> 31) {
+            0 => Rng(0x9E37_79B9_7F4A_7C15),
+            state => Rng(state),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+// Always the same shape.
+pub struct Fixed(pub Shape);
+
+impl Strategy for Fixed {
+    fn throw(&mut self) -> Shape {
+        self.0
+    }
+}
+
+pub struct Random(pub Rng);
+
+impl Strategy for Random {
+    fn throw(&mut self) -> Shape {
+        Shape::ALL[(self.0.next_u64() % 3) as usize]
+    }
+}
+
+// Throws whatever beats the shape the other side has thrown most so far.
+#[derive(Default)]
+pub struct FrequencyCounter {
+    seen: [u64; 3],
+}
+
+impl Strategy for FrequencyCounter {
+    fn throw(&mut self) -> Shape {
+        let (favourite, _) = Shape::ALL
+            .iter()
+            .zip(self.seen)
+            .fold((Shape::Rock, 0), |best, (&s, n)| if n > best.1 { (s, n) } else { best });
+        favourite.loses_to()
+    }
+
+    fn observe(&mut self, _ours: Shape, theirs: Shape, _outcome: Outcome) {
+        let i = Shape::ALL.iter().position(|&s| s == theirs).unwrap();
+        self.seen[i] += 1;
+    }
+}
+
+// Keeps throwing a shape while it wins, and otherwise switches to whatever
+// would have beaten the other side's last throw.
+pub struct WinStayLoseShift {
+    next: Shape,
+}
+
+impl Default for WinStayLoseShift {
+    fn default() -> WinStayLoseShift {
+        WinStayLoseShift { next: Shape::Rock }
+    }
+}
+
+impl Strategy for WinStayLoseShift {
+    fn throw(&mut self) -> Shape {
+        self.next
+    }
+
+    fn observe(&mut self, ours: Shape, theirs: Shape, outcome: Outcome) {
+        self.next = match outcome {
+            Outcome::Win => ours,
+            _ => theirs.loses_to(),
+        };
+    }
+}
+
+// Plays a list of throws (one column of the strategy guide, say) over and
+// over.  There has to be at least one throw to play.
+pub struct Replay {
+    throws: Vec<Shape>,
+    next: usize,
+}
+
+impl Replay {
+    pub fn new(throws: Vec<Shape>) -> Option<Replay> {
+        (!throws.is_empty()).then_some(Replay { throws, next: 0 })
+    }
+}
+
+impl Strategy for Replay {
+    fn throw(&mut self) -> Shape {
+        let shape = self.throws[self.next];
+        self.next = (self.next + 1) % self.throws.len();
+        shape
+    }
+}
+
+// A strategy as it's asked for on the command line, which can build as
+// many fresh players as a tournament needs:
+//
+//     rock, paper, scissors   always throw that
+//     random:SEED             throw at random
+//     frequency               counter the other side's favourite
+//     wsls                    win-stay, lose-shift
+//     guide                   replay the elves' column of the guide
+#[derive(Clone, Debug, PartialEq)]
+pub enum Player {
+    Fixed(Shape),
+    Random(u64),
+    FrequencyCounter,
+    WinStayLoseShift,
+    Guide,
+}
+
+impl Player {
+    // guide is the elves' column of the strategy guide, for Player::Guide,
+    // which can't play from an empty one.
+    pub fn build(&self, guide: &[Shape]) -> Result<Box<dyn Strategy>, String> {
+        Ok(match self {
+            Player::Fixed(shape) => Box::new(Fixed(*shape)),
+            Player::Random(seed) => Box::new(Random(Rng::new(*seed))),
+            Player::FrequencyCounter => Box::<FrequencyCounter>::default(),
+            Player::WinStayLoseShift => Box::<WinStayLoseShift>::default(),
+            Player::Guide => {
+                Box::new(Replay::new(guide.to_vec()).ok_or_else(|| "the strategy guide is empty".to_string())?)
+            }
+        })
+    }
+}
+
+impl FromStr for Player {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Player, String> {
+        match s {
+            "rock" => Ok(Player::Fixed(Shape::Rock)),
+            "paper" => Ok(Player::Fixed(Shape::Paper)),
+            "scissors" => Ok(Player::Fixed(Shape::Scissors)),
+            "frequency" => Ok(Player::FrequencyCounter),
+            "wsls" => Ok(Player::WinStayLoseShift),
+            "guide" => Ok(Player::Guide),
+            _ => match s.strip_prefix("random:").map(str::parse) {
+                Some(Ok(seed)) => Ok(Player::Random(seed)),
+                _ => Err(format!("no such strategy: {:?}", s)),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Player::Fixed(shape) => write!(f, "{}", format!("{:?}", shape).to_lowercase()),
+            Player::Random(seed) => write!(f, "random:{}", seed),
+            Player::FrequencyCounter => write!(f, "frequency"),
+            Player::WinStayLoseShift => write!(f, "wsls"),
+            Player::Guide => write!(f, "guide"),
+        }
+    }
+}
+
+// How one side of a match (or a whole tournament) went.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Tally {
+    pub wins: u64,
+    pub draws: u64,
+    pub losses: u64,
+    pub score: i64,
+}
+
+impl Tally {
+    fn record(&mut self, outcome: Outcome, score: i32) {
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Lose => self.losses += 1,
+        }
+        self.score += score as i64;
+    }
+
+    fn add(&mut self, other: &Tally) {
+        self.wins += other.wins;
+        self.draws += other.draws;
+        self.losses += other.losses;
+        self.score += other.score;
+    }
+}
+
+// Plays a against b, scoring both sides the way the puzzle scores us.
+pub fn play(a: &mut dyn Strategy, b: &mut dyn Strategy, rounds: usize) -> (Tally, Tally) {
+    let mut tallies = (Tally::default(), Tally::default());
+    for _ in 0..rounds {
+        let (ta, tb) = (a.throw(), b.throw());
+        let (oa, ob) = (game_result((tb, ta)), game_result((ta, tb)));
+        tallies.0.record(oa, total_score((tb, ta)));
+        tallies.1.record(ob, total_score((ta, tb)));
+        a.observe(ta, tb, oa);
+        b.observe(tb, ta, ob);
+    }
+    tallies
+}
+
+// Everyone plays everyone else once, with fresh players for every match.
+pub struct Tournament {
+    pub players: Vec<Player>,
+    // (a, b, how a did, how b did), indexing into players.
+    pub matches: Vec<(usize, usize, Tally, Tally)>,
+}
+
+impl Tournament {
+    pub fn run(players: Vec<Player>, guide: &[Shape], rounds: usize) -> Result<Tournament, String> {
+        let mut matches = Vec::new();
+        for a in 0..players.len() {
+            for b in a + 1..players.len() {
+                let (ta, tb) = play(&mut *players[a].build(guide)?, &mut *players[b].build(guide)?, rounds);
+                matches.push((a, b, ta, tb));
+            }
+        }
+        Ok(Tournament { players, matches })
+    }
+
+    // Each player's results over all their matches, best score first.
+    pub fn standings(&self) -> Vec<(&Player, Tally)> {
+        let mut totals = vec![Tally::default(); self.players.len()];
+        for (a, b, ta, tb) in &self.matches {
+            totals[*a].add(ta);
+            totals[*b].add(tb);
+        }
+        let mut standings: Vec<(&Player, Tally)> = self.players.iter().zip(totals).collect();
+        standings.sort_by_key(|(_, t)| std::cmp::Reverse(t.score));
+        standings
+    }
+}
+
+impl fmt::Display for Tournament {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:<14} {:<14} {:>7} {:>7} {:>7} {:>9} {:>9}", "player", "opponent", "won", "drawn", "lost", "score", "theirs")?;
+        for (a, b, ta, tb) in &self.matches {
+            writeln!(
+                f,
+                "{:<14} {:<14} {:>7} {:>7} {:>7} {:>9} {:>9}",
+                self.players[*a].to_string(),
+                self.players[*b].to_string(),
+                ta.wins,
+                ta.draws,
+                ta.losses,
+                ta.score,
+                tb.score
+            )?;
+        }
+        writeln!(f)?;
+        write!(f, "{:<14} {:>7} {:>7} {:>7} {:>9}", "standings", "won", "drawn", "lost", "score")?;
+        for (player, t) in self.standings() {
+            write!(f, "\n{:<14} {:>7} {:>7} {:>7} {:>9}", player.to_string(), t.wins, t.draws, t.losses, t.score)?;
+        }
+        Ok(())
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_fixed_strategies() {
+    let (rock, scissors) = play(&mut Fixed(Shape::Rock), &mut Fixed(Shape::Scissors), 10);
+    assert_eq!(rock, Tally { wins: 10, draws: 0, losses: 0, score: 70 });
+    assert_eq!(scissors, Tally { wins: 0, draws: 0, losses: 10, score: 30 });
+}
+
+#[test]
+fn test_adaptive_strategies_exploit_fixed_ones() {
+    // Once it has seen scissors, the frequency counter throws rock forever.
+    let (_, counter) = play(&mut Fixed(Shape::Scissors), &mut FrequencyCounter::default(), 100);
+    assert_eq!(counter.wins, 99);
+
+    // Win-stay-lose-shift loses once at most, then settles on the winner.
+    let (_, wsls) = play(&mut Fixed(Shape::Scissors), &mut WinStayLoseShift::default(), 100);
+    assert_eq!(wsls.wins, 100);
+    let (_, wsls) = play(&mut Fixed(Shape::Paper), &mut WinStayLoseShift::default(), 100);
+    assert_eq!(wsls.wins, 99);
+}
+
+#[test]
+fn test_random_is_reproducible() {
+    let throws = |seed| {
+        let mut r = Random(Rng::new(seed));
+        (0..50).map(|_| r.throw()).collect::<Vec<Shape>>()
+    };
+    assert_eq!(throws(7), throws(7));
+    assert_ne!(throws(7), throws(8));
+    // Every seed gets going, including the ones that used to land on
+    // xorshift's zero.
+    for seed in [0, 1, 0x9E37_79B9_7F4A_7C15, u64::MAX] {
+        assert!(Shape::ALL.iter().all(|s| throws(seed).contains(s)), "seed {}", seed);
+    }
+}
+
+#[test]
+fn test_tournament() {
+    let players: Vec<Player> = ["rock", "paper", "guide", "random:1"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let guide = vec![Shape::Scissors];
+    let tournament = Tournament::run(players, &guide, 30).unwrap();
+
+    assert_eq!(tournament.matches.len(), 6);
+    for (_, _, a, b) in &tournament.matches {
+        assert_eq!(a.wins, b.losses);
+        assert_eq!(a.draws, b.draws);
+        assert_eq!(a.wins + a.draws + a.losses, 30);
+    }
+
+    // Replaying a guide of nothing but scissors is no match for rock.
+    let (_, _, rock, guide) = tournament.matches[1];
+    assert_eq!((rock.wins, guide.losses), (30, 30));
+
+    let standings = tournament.standings();
+    assert_eq!(standings.len(), 4);
+    assert!(standings.windows(2).all(|w| w[0].1.score >= w[1].1.score));
+    assert!("random:x".parse::<Player>().is_err());
+}
+
+#[test]
+fn test_empty_guide() {
+    assert!(Replay::new(Vec::new()).is_none());
+    assert_eq!(Player::Guide.build(&[]).err(), Some("the strategy guide is empty".to_string()));
+    assert!(Player::FrequencyCounter.build(&[]).is_ok());
+
+    let players = vec![Player::Fixed(Shape::Rock), Player::Guide];
+    assert!(Tournament::run(players, &[], 10).is_err());
+    // Without the guide player, nobody needs a guide.
+    let players = vec![Player::Fixed(Shape::Rock), Player::WinStayLoseShift];
+    assert_eq!(Tournament::run(players, &[], 10).unwrap().matches.len(), 1);
+}
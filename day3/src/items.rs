@@ -0,0 +1,153 @@
+use std::fmt;
+
+use crate::{item, priority};
+
+// A set of rucksack items, kept as one bit per priority (bit 1 for 'a' up to
+// bit 52 for 'Z').  Finding what a pile of compartments have in common is
+// then one pass over each to build its set and an AND per compartment,
+// rather than searching one string for every character of another.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub fn new() -> ItemSet {
+        ItemSet(0)
+    }
+
+    // Every item there is.
+    pub fn all() -> ItemSet {
+        ItemSet(((1 << 52) - 1) << 1)
+    }
+
+    // Anything that isn't an item has no bit to set, so it's left out.
+    // Rucksacks are checked for that long before they get here.
+    pub fn insert(&mut self, c: char) {
+        if let Some(p) = priority(c) {
+            self.0 |= 1 << p;
+        }
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        priority(c).is_some_and(|p| self.0 & (1 << p) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn intersection(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    // The items in every one of the sets.  No sets at all have nothing in
+    // common, rather than everything.
+    pub fn common<I: IntoIterator<Item = ItemSet>>(sets: I) -> ItemSet {
+        let mut sets = sets.into_iter();
+        match sets.next() {
+            Some(first) => sets.fold(first, |acc, s| acc.intersection(&s)),
+            None => ItemSet::new(),
+        }
+    }
+
+    // The items in priority order.
+    pub fn iter(&self) -> Items {
+        Items(self.0)
+    }
+
+    // The items' priorities, lowest first.
+    pub fn priorities(&self) -> impl Iterator<Item = u8> {
+        self.iter().map(|c| priority(c).unwrap())
+    }
+
+    pub fn priority_sum(&self) -> u32 {
+        self.priorities().map(|p| p as u32).sum()
+    }
+}
+
+impl From<&str> for ItemSet {
+    fn from(s: &str) -> ItemSet {
+        s.chars().collect()
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(items: I) -> ItemSet {
+        let mut set = ItemSet::new();
+        for c in items {
+            set.insert(c);
+        }
+        set
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = char;
+    type IntoIter = Items;
+
+    fn into_iter(self) -> Items {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// Walks the set bits lowest first, clearing each as it goes.
+pub struct Items(u64);
+
+impl Iterator for Items {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.0 == 0 {
+            return None;
+        }
+        let p = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Some(item(p))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.0.count_ones() as usize;
+        (n, Some(n))
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_item_set() {
+    let a = ItemSet::from("vJrwpWtwJgWr");
+    let b = ItemSet::from("hcsFMMfFFhFp");
+    assert_eq!(a.len(), 8);
+    assert!(a.contains('J') && !a.contains('j'));
+    assert_eq!(a.intersection(&b).iter().collect::<String>(), "p");
+    assert_eq!(ItemSet::from("zAa").iter().collect::<String>(), "azA");
+    assert_eq!(ItemSet::from("a1 é").iter().collect::<String>(), "a");
+    assert!(!a.contains('1'));
+    assert_eq!(a.union(&b).len(), a.len() + b.len() - 1);
+    assert_eq!(ItemSet::all().len(), 52);
+    assert_eq!(ItemSet::all().iter().last(), Some('Z'));
+}
+
+#[test]
+fn test_common_items() {
+    let sets = ["abcX", "bcdX", "cXyz"].map(ItemSet::from);
+    assert_eq!(ItemSet::common(sets).iter().collect::<String>(), "cX");
+    assert_eq!(ItemSet::common(sets).priority_sum(), 3 + 50);
+    assert!(ItemSet::common([ItemSet::from("ab"), ItemSet::from("cd")]).is_empty());
+    assert!(ItemSet::common([]).is_empty());
+}
@@ -0,0 +1,243 @@
+use aoc_common::{exact_chunks, AocError, Input, Solution};
+
+pub mod items;
+pub mod validate;
+
+use items::ItemSet;
+use validate::Problem;
+
+// Only letters are items, so anything else has no priority.
+fn priority(c: char) -> Option<u8> {
+    match c {
+        'a'..='z' => Some(c as u8 - b'a' + 1),
+        'A'..='Z' => Some(c as u8 - b'A' + 27),
+        _ => None,
+    }
+}
+
+// The item with priority p, the other way round from priority().
+fn item(p: u8) -> char {
+    if p <= 26 {
+        (p + 96) as char
+    } else {
+        (p + 38) as char
+    }
+}
+
+// Whatever is in every one of the compartments.
+fn common_items(compartments: &[&str]) -> ItemSet {
+    ItemSet::common(compartments.iter().map(|&c| ItemSet::from(c)))
+}
+
+fn check_backpack(backpack: &str) -> Result<(), Problem> {
+    match backpack.chars().find(|&c| priority(c).is_none()) {
+        Some(c) => Err(Problem::NotAnItem(c)),
+        None => Ok(())
+    }
+}
+
+// Splits s into k runs of the same number of characters (not bytes, so
+// nothing gets cut in half).
+fn split_evenly(s: &str, k: usize) -> Result<Vec<&str>, Problem> {
+    let len = s.chars().count();
+    if k == 0 || !len.is_multiple_of(k) {
+        return Err(Problem::Uneven(len, k));
+    }
+    let size = len / k;
+    let mut parts = Vec::with_capacity(k);
+    let mut rest = s;
+    for _ in 0..k {
+        let end = rest.char_indices().nth(size).map_or(rest.len(), |(i, _)| i);
+        let (part, tail) = rest.split_at(end);
+        parts.push(part);
+        rest = tail;
+    }
+    Ok(parts)
+}
+
+// One elf's rucksack, and the line of the input it came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    pub line: usize,
+    pub items: String,
+}
+
+impl Rucksack {
+    pub fn new(line: usize, items: &str) -> Result<Rucksack, AocError> {
+        check_backpack(items).map_err(|p| AocError::parse(line, items, p.to_string()))?;
+        Ok(Rucksack { line, items: items.to_string() })
+    }
+
+    pub fn contents(&self) -> ItemSet {
+        ItemSet::from(self.items.as_str())
+    }
+
+    // The items split evenly between k compartments.
+    pub fn compartments(&self, k: usize) -> Result<Vec<&str>, AocError> {
+        split_evenly(&self.items, k).map_err(|p| AocError::parse(self.line, &self.items, p.to_string()))
+    }
+
+    // Whatever got packed into all k compartments.  In the puzzle's two
+    // compartment rucksacks that should be exactly one item.
+    pub fn misplaced(&self, k: usize) -> Result<ItemSet, AocError> {
+        Ok(common_items(&self.compartments(k)?))
+    }
+}
+
+// Everything that every elf in the group is carrying.  One of those ought to
+// be the group's badge.
+pub fn group_badge(group: &[Rucksack]) -> ItemSet {
+    ItemSet::common(group.iter().map(Rucksack::contents))
+}
+
+// The possible badges for each group of size elves, taking the rucksacks in
+// order.  Every elf has to be in a full group.
+pub fn group_badges(rucksacks: &[Rucksack], size: usize) -> Result<Vec<ItemSet>, AocError> {
+    if size == 0 {
+        return Err(AocError::Invalid("a group needs at least one elf".to_string()));
+    }
+    let groups = exact_chunks(rucksacks, size).map_err(|r| {
+        AocError::Invalid(format!(
+            "line {}: the last group has {} elves, not {}",
+            rucksacks[r.start()].line,
+            r.left_over(),
+            size
+        ))
+    })?;
+    Ok(groups.map(group_badge).collect())
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[cfg(test)]
+fn rucksack(items: &str) -> Rucksack {
+    Rucksack::new(1, items).unwrap()
+}
+
+#[cfg(test)]
+fn first_dupe(items: &str) -> Option<char> {
+    rucksack(items).misplaced(2).unwrap().iter().next()
+}
+
+#[test]
+fn test_split_backpack() {
+    assert_eq!(rucksack("vJrwpWtwJgWrhcsFMMfFFhFp").compartments(2).unwrap(), vec!["vJrwpWtwJgWr", "hcsFMMfFFhFp"]);
+    assert_eq!(rucksack("abcdef").compartments(3).unwrap(), vec!["ab", "cd", "ef"]);
+    assert_eq!(rucksack("").compartments(2).unwrap(), vec!["", ""]);
+    assert!(rucksack("abcde").compartments(2).is_err());
+    assert!(rucksack("abcd").compartments(0).is_err());
+
+    // Only letters make it into a rucksack, but splitting mustn't cut a
+    // character in half whatever it's given.
+    assert_eq!(split_evenly("aébc", 2), Ok(vec!["aé", "bc"]));
+    assert_eq!(split_evenly("ééé", 3), Ok(vec!["é", "é", "é"]));
+    assert_eq!(split_evenly("éa", 3), Err(Problem::Uneven(2, 3)));
+}
+
+#[test]
+fn test_priorities() {
+    assert_eq!(priority('c'), Some(3));
+    assert_eq!(priority('m'), Some(13));
+    assert_eq!(priority('D'), Some(30));
+    assert_eq!(priority('Z'), Some(52));
+    assert!(('a'..='z').chain('A'..='Z').all(|c| item(priority(c).unwrap()) == c));
+    assert_eq!(priority('1'), None);
+    assert_eq!(priority(' '), None);
+    assert_eq!(priority('é'), None);
+}
+
+#[test]
+fn test_detect_common_item() {
+    let common = common_items(&["vJrwpWtwJgWr", "hcsFMMfFFhFp"]).iter().next().unwrap();
+    assert_eq!(common, 'p');
+
+    let common2 = common_items(&["jqHRNqRjqzjGDLGL", "rsFMfFZSrLrFZsSL"]).iter().next().unwrap();
+    assert_eq!(common2, 'L');
+
+    let common3 = common_items(&["PmmdzqPrV", "vPwwTWBwg"]).iter().next().unwrap();
+    assert_eq!(common3, 'P');
+}
+
+#[test]
+fn test_split_and_detect() {
+    assert_eq!(first_dupe("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn"), Some('v'));
+    assert_eq!(first_dupe("ttgJtRGJQctTZtZT"), Some('t'));
+}
+
+#[test]
+fn test_detect_dupe_item() {
+    assert_eq!(first_dupe("CrZsJsPPZsGzwwsLwLmpwMDw"), Some('s'));
+    assert_eq!(rucksack("abcabcab").misplaced(4).unwrap().iter().collect::<String>(), "");
+    assert_eq!(rucksack("abbaabba").misplaced(4).unwrap().iter().collect::<String>(), "ab");
+}
+
+#[test]
+fn test_nothing_in_common() {
+    assert_eq!(first_dupe("abcd"), None);
+    assert!(group_badge(&[rucksack("ab"), rucksack("cd")]).is_empty());
+    assert_eq!(check_backpack("ab1c"), Err(Problem::NotAnItem('1')));
+    assert!(Rucksack::new(4, "ab1c").is_err());
+}
+
+#[test]
+fn test_group_badges() {
+    let rucksacks: Vec<Rucksack> = ["vJrwpWtwJgWrhcsFMMfFFhFp", "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL", "PmmdzqPrVvPwwTWBwg"]
+        .iter()
+        .enumerate()
+        .map(|(i, r)| Rucksack::new(i + 1, r).unwrap())
+        .collect();
+
+    let badges = group_badges(&rucksacks, 3).unwrap();
+    assert_eq!(badges.len(), 1);
+    assert_eq!(badges[0].iter().collect::<String>(), "r");
+
+    // In pairs, the first two elves share more than a badge's worth.
+    let pair = group_badge(&rucksacks[..2]);
+    assert_eq!(pair.iter().collect::<String>(), "frsFM");
+    assert_eq!(group_badges(&rucksacks, 1).unwrap()[2], rucksacks[2].contents());
+
+    assert!(matches!(group_badges(&rucksacks, 2), Err(AocError::Invalid(m)) if m.starts_with("line 3:")));
+    assert!(group_badges(&rucksacks, 0).is_err());
+}
+
+//-----------------------------------------------------------------------------
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: Input) -> Result<Vec<Rucksack>, AocError> {
+        input
+            .lines()?
+            .iter()
+            .enumerate()
+            .map(|(i, line)| Rucksack::new(i + 1, line))
+            .collect()
+    }
+
+    fn part1(rucksacks: &Vec<Rucksack>) -> Result<String, AocError> {
+        let priorities: Vec<u8> = rucksacks
+            .iter()
+            .map(|r| r.misplaced(2)?.priorities().next().ok_or_else(|| {
+                AocError::NoAnswer(format!("line {}: no item is in both compartments", r.line))
+            }))
+            .collect::<Result<_, _>>()?;
+
+        Ok(format!("{}", priorities.iter().map(|&n| n as u32).sum::<u32>()))
+    }
+
+    fn part2(rucksacks: &Vec<Rucksack>) -> Result<String, AocError> {
+        let part_two_priorities: Vec<u8> = group_badges(rucksacks, 3)?
+            .iter()
+            .enumerate()
+            .map(|(i, badges)| badges.priorities().next().ok_or_else(|| {
+                AocError::NoAnswer(format!("lines {}-{}: the group has no badge", 3 * i + 1, 3 * i + 3))
+            }))
+            .collect::<Result<_, _>>()?;
+
+        Ok(format!("{}", part_two_priorities.iter().map(|&n| n as u32).sum::<u32>()))
+    }
+}
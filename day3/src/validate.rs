@@ -0,0 +1,245 @@
+use std::fmt;
+
+use aoc_common::exact_chunks;
+
+use crate::items::ItemSet;
+use crate::{check_backpack, group_badge, priority, split_evenly, Rucksack};
+
+// What can be wrong with a line of the rucksack list, or with a group of
+// them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    NotAnItem(char),
+    // (items, compartments)
+    Uneven(usize, usize),
+    NoCommonItem,
+    SeveralCommonItems(ItemSet),
+    NoBadge,
+    SeveralBadges(ItemSet),
+    // (elves, group size)
+    ShortGroup(usize, usize),
+}
+
+impl Problem {
+    // Whether it's about a group of lines rather than one of them.
+    pub fn is_group(&self) -> bool {
+        matches!(self, Problem::NoBadge | Problem::SeveralBadges(_) | Problem::ShortGroup(_, _))
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::NotAnItem(c) => write!(f, "{:?} is not an item", c),
+            Problem::Uneven(len, k) => write!(f, "{} items won't split evenly into {} compartments", len, k),
+            Problem::NoCommonItem => write!(f, "no item is in both compartments"),
+            Problem::SeveralCommonItems(items) => {
+                write!(f, "{} items are in both compartments: {}", items.len(), items.iter().collect::<String>())
+            }
+            Problem::NoBadge => write!(f, "the group has no badge"),
+            Problem::SeveralBadges(items) => {
+                write!(f, "the group could have {} badges: {}", items.len(), items.iter().collect::<String>())
+            }
+            Problem::ShortGroup(elves, size) => write!(f, "the last group has {} elves, not {}", elves, size),
+        }
+    }
+}
+
+// A problem, and where it is.  A group's problem runs from line to
+// last_line, and its text is all of their lines.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub last_line: usize,
+    pub text: String,
+    pub problem: Problem,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.last_line == self.line {
+            write!(f, "line {}: {}", self.line, self.problem)?;
+        } else {
+            write!(f, "lines {}-{}: {}", self.line, self.last_line, self.problem)?;
+        }
+        if !self.problem.is_group() {
+            write!(f, ": {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+// Checks a line the way part 1 reads it: letters only, split into two
+// halves that share exactly one item.  That item comes back with the
+// rucksack.
+pub fn check_line(line: usize, text: &str) -> Result<(Rucksack, char), Diagnostic> {
+    let diagnose = |problem| Diagnostic { line, last_line: line, text: text.to_string(), problem };
+    check_backpack(text).map_err(diagnose)?;
+    let halves = split_evenly(text, 2).map_err(diagnose)?;
+    let common = ItemSet::common(halves.into_iter().map(ItemSet::from));
+    match common.len() {
+        0 => Err(diagnose(Problem::NoCommonItem)),
+        1 => Ok((Rucksack { line, items: text.to_string() }, common.iter().next().unwrap())),
+        _ => Err(diagnose(Problem::SeveralCommonItems(common))),
+    }
+}
+
+// Every line of the list checked, in order, then every group of three.
+// Rather than stopping at the first problem this goes through them all,
+// and can still come up with answers from whatever is left.
+pub struct Validation {
+    pub lines: Vec<Result<(Rucksack, char), Diagnostic>>,
+    // The badge of every group that has one, and isn't already let down by
+    // a bad line.
+    pub badges: Vec<char>,
+    // What's wrong with the rest of the groups (other than a bad line),
+    // including a short one at the end.
+    pub groups: Vec<Diagnostic>,
+}
+
+impl Validation {
+    pub fn new(lines: &[String]) -> Validation {
+        let checked: Vec<_> = lines.iter().enumerate().map(|(i, l)| check_line(i + 1, l)).collect();
+        let mut validation = Validation { lines: checked, badges: Vec::new(), groups: Vec::new() };
+        let diagnose = |first: usize, group: &[String], problem| Diagnostic {
+            line: first + 1,
+            last_line: first + group.len(),
+            text: group.join("\n"),
+            problem,
+        };
+
+        let whole = match exact_chunks(lines, 3) {
+            Ok(_) => lines.len(),
+            Err(ragged) => {
+                let short = Problem::ShortGroup(ragged.left_over(), 3);
+                validation.groups.push(diagnose(ragged.start(), &lines[ragged.start()..], short));
+                ragged.start()
+            }
+        };
+        for (i, group) in exact_chunks(&validation.lines[..whole], 3).unwrap().enumerate() {
+            let rucksacks: Option<Vec<Rucksack>> = group.iter().map(|l| l.as_ref().ok().map(|(r, _)| r.clone())).collect();
+            // A group with a bad line has been reported already, and there's
+            // no telling what its badge should have been.
+            let Some(rucksacks) = rucksacks else { continue };
+            let badge = group_badge(&rucksacks);
+            let text = &lines[3 * i..3 * i + 3];
+            match badge.len() {
+                0 => validation.groups.push(diagnose(3 * i, text, Problem::NoBadge)),
+                1 => validation.badges.push(badge.iter().next().unwrap()),
+                _ => validation.groups.push(diagnose(3 * i, text, Problem::SeveralBadges(badge))),
+            }
+        }
+        validation
+    }
+
+    // Everything that's wrong, in order of where it starts.
+    pub fn problems(&self) -> Vec<&Diagnostic> {
+        let mut problems: Vec<&Diagnostic> = self
+            .lines
+            .iter()
+            .filter_map(|l| l.as_ref().err())
+            .chain(&self.groups)
+            .collect();
+        problems.sort_by_key(|d| d.line);
+        problems
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.problems().is_empty()
+    }
+
+    // Part 1, leaving out the bad lines.
+    pub fn part1(&self) -> u32 {
+        self.lines
+            .iter()
+            .flatten()
+            .map(|(_, c)| priority(*c).unwrap() as u32)
+            .sum()
+    }
+
+    // Part 2, leaving out any group of three with a problem, or a bad line,
+    // in it.
+    pub fn part2(&self) -> u32 {
+        self.badges.iter().map(|&c| priority(c).unwrap() as u32).sum()
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_check_line() {
+    assert_eq!(check_line(1, "vJrwpWtwJgWrhcsFMMfFFhFp").unwrap().1, 'p');
+
+    let problem = |text| check_line(7, text).unwrap_err().problem;
+    assert_eq!(problem("ab1ab1"), Problem::NotAnItem('1'));
+    assert_eq!(problem("abcab"), Problem::Uneven(5, 2));
+    assert_eq!(problem("abcd"), Problem::NoCommonItem);
+    assert_eq!(problem("abab"), Problem::SeveralCommonItems(ItemSet::from("ab")));
+    // Multi-byte characters are reported rather than sliced through.
+    assert_eq!(problem("aéa"), Problem::NotAnItem('é'));
+
+    assert_eq!(
+        check_line(7, "abcd").unwrap_err().to_string(),
+        "line 7: no item is in both compartments: \"abcd\""
+    );
+}
+
+#[test]
+fn test_validation() {
+    let lines: Vec<String> = [
+        "vJrwpWtwJgWrhcsFMMfFFhFp",
+        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+        "PmmdzqPrVvPwwTWBwg",
+        "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+        "ttgJtRGJQctTZtZT",
+        "CrZsJsPPZsGzwwsLwLmp-MDw",
+        "abc",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+
+    let validation = Validation::new(&lines);
+    assert!(!validation.is_valid());
+    let bad: Vec<usize> = validation.problems().iter().map(|d| d.line).collect();
+    assert_eq!(bad, vec![6, 7, 7]);
+
+    // p, L, P, v and t from the good lines, and only the first group's
+    // badge, r.
+    assert_eq!(validation.part1(), 16 + 38 + 42 + 22 + 20);
+    assert_eq!(validation.part2(), 18);
+
+    assert!(Validation::new(&lines[..3]).is_valid());
+}
+
+#[cfg(test)]
+fn group_problems(lines: &[&str]) -> Vec<String> {
+    let lines: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
+    let validation = Validation::new(&lines);
+    // Every line is fine on its own.
+    assert!(validation.lines.iter().all(Result::is_ok));
+    validation.problems().iter().map(|d| d.to_string()).collect()
+}
+
+#[test]
+fn test_group_problems() {
+    assert_eq!(group_problems(&["abac", "dedf", "ghgi"]), ["lines 1-3: the group has no badge"]);
+    assert_eq!(group_problems(&["axya", "bxyb", "cxyc"]), ["lines 1-3: the group could have 2 badges: xy"]);
+    assert_eq!(
+        group_problems(&["vJrwpWtwJgWrhcsFMMfFFhFp", "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL", "PmmdzqPrVvPwwTWBwg", "abca"]),
+        ["line 4: the last group has 1 elves, not 3"]
+    );
+    assert_eq!(
+        group_problems(&["abac", "dedf", "ghgi", "abca", "abca"]),
+        ["lines 1-3: the group has no badge", "lines 4-5: the last group has 2 elves, not 3"]
+    );
+    assert!(group_problems(&[]).is_empty());
+
+    // Only the group that has a badge counts towards part 2.
+    let lines: Vec<String> = ["abac", "dedf", "ghgi", "abca", "cdcx", "cefc"].iter().map(|s| s.to_string()).collect();
+    let validation = Validation::new(&lines);
+    assert_eq!(validation.badges, ['c']);
+    assert_eq!(validation.part2(), 3);
+}
@@ -0,0 +1,243 @@
+use std::fmt;
+
+use crate::interval::{Interval, Section};
+
+// Sections as a list of intervals that neither overlap nor touch, in order.
+// Adding an interval merges it with anything it runs into, so the same
+// sections always end up listed the same way.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T: Ord> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Section> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: Vec::new() }
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // Everything before first ends before interval starts, with a gap;
+        // everything from last on starts after it, with a gap.  What's in
+        // between gets merged.
+        let first = self
+            .intervals
+            .partition_point(|i| i.end() < interval.start() && !i.adjacent(&interval));
+        let last = first
+            + self.intervals[first..]
+                .iter()
+                .take_while(|i| i.union(&interval).is_some())
+                .count();
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |acc, i| acc.union(i).unwrap());
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    // How many sections there are in all.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains_section(&self, section: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end() < section);
+        self.intervals.get(i).is_some_and(|i| i.contains_section(section))
+    }
+
+    // The sections in within that aren't in the set.
+    pub fn gaps(&self, within: &Interval<T>) -> IntervalSet<T> {
+        let mut gaps = IntervalSet::new();
+        // The first section that could still be in a gap, if there is one.
+        let mut from = Some(within.start());
+        for i in &self.intervals {
+            let Some(start) = from else { break };
+            if i.start() > within.end() {
+                break;
+            }
+            if i.end() < start {
+                continue;
+            }
+            if let Some(gap) = i.start().prev().and_then(|end| Interval::new(start, end)) {
+                gaps.intervals.push(gap);
+            }
+            from = i.end().next();
+        }
+        if let Some(gap) = from.and_then(|start| Interval::new(start, within.end())) {
+            gaps.intervals.push(gap);
+        }
+        gaps
+    }
+}
+
+impl<T: Section> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> IntervalSet<T> {
+        let mut sorted: Vec<Interval<T>> = intervals.into_iter().collect();
+        sorted.sort();
+        let mut set = IntervalSet::new();
+        for interval in sorted {
+            match set.intervals.last_mut().and_then(|last| last.union(&interval).map(|u| (last, u))) {
+                Some((last, merged)) => *last = merged,
+                None => set.intervals.push(interval),
+            }
+        }
+        set
+    }
+}
+
+impl<T: Ord + fmt::Display> fmt::Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let intervals: Vec<String> = self.intervals.iter().map(|i| i.to_string()).collect();
+        write!(f, "{}", intervals.join(", "))
+    }
+}
+
+// How many intervals cover each section, as runs of sections that are all
+// covered the same number of times.  Sections nobody covers are left out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Coverage<T: Ord> {
+    runs: Vec<(Interval<T>, usize)>,
+}
+
+impl<T: Section> Coverage<T> {
+    // A sweep over the ends of the intervals.  Each interval opens at its
+    // start and closes after its end, so at any one section the opens come
+    // before the closes.
+    pub fn new(intervals: &[Interval<T>]) -> Coverage<T> {
+        let mut ends: Vec<(T, bool)> = intervals
+            .iter()
+            .flat_map(|i| [(i.start(), false), (i.end(), true)])
+            .collect();
+        ends.sort();
+
+        let mut coverage = Coverage { runs: Vec::new() };
+        let mut depth = 0;
+        // The first section not in a run yet, if there are any left.
+        let mut from = ends.first().map(|(section, _)| *section);
+        for (section, closes) in ends {
+            let Some(start) = from else { break };
+            if closes {
+                coverage.push(Interval::new(start, section), depth);
+                depth -= 1;
+                from = section.next();
+            } else {
+                coverage.push(section.prev().and_then(|end| Interval::new(start, end)), depth);
+                depth += 1;
+                from = Some(section);
+            }
+        }
+        coverage
+    }
+
+    fn push(&mut self, run: Option<Interval<T>>, depth: usize) {
+        let Some(run) = run.filter(|_| depth > 0) else { return };
+        match self.runs.last_mut() {
+            Some((last, d)) if *d == depth && last.adjacent(&run) => *last = last.union(&run).unwrap(),
+            _ => self.runs.push((run, depth)),
+        }
+    }
+
+    pub fn runs(&self) -> &[(Interval<T>, usize)] {
+        &self.runs
+    }
+
+    pub fn depth(&self, section: T) -> usize {
+        let i = self.runs.partition_point(|(run, _)| run.end() < section);
+        match self.runs.get(i) {
+            Some((run, depth)) if run.contains_section(section) => *depth,
+            _ => 0,
+        }
+    }
+
+    // Every section covered at all.
+    pub fn covered(&self) -> IntervalSet<T> {
+        self.more_than(0)
+    }
+
+    // The sections covered more than k times.
+    pub fn more_than(&self, k: usize) -> IntervalSet<T> {
+        self.runs.iter().filter(|(_, d)| *d > k).map(|(run, _)| *run).collect()
+    }
+
+    // The first run of the most heavily covered sections, and how heavily.
+    pub fn busiest(&self) -> Option<(Interval<T>, usize)> {
+        self.runs.iter().copied().fold(None, |best, (run, d)| match best {
+            Some((_, most)) if most >= d => best,
+            _ => Some((run, d)),
+        })
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[cfg(test)]
+fn intervals(text: &str) -> Vec<Interval<u8>> {
+    text.split(',').map(|s| s.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_interval_set() {
+    let set: IntervalSet<u8> = intervals("6-8,2-4,5-5,12-14,20-30,13-22").into_iter().collect();
+    assert_eq!(set.to_string(), "2-8, 12-30");
+    assert_eq!(set.len(), 7 + 19);
+    assert!(set.contains_section(12) && !set.contains_section(10));
+
+    let mut inserted = IntervalSet::new();
+    for i in intervals("6-8,2-4,5-5,12-14,20-30,13-22") {
+        inserted.insert(i);
+    }
+    assert_eq!(inserted, set);
+    inserted.insert("9-11".parse().unwrap());
+    assert_eq!(inserted.to_string(), "2-30");
+
+    let within = "1-40".parse().unwrap();
+    assert_eq!(set.gaps(&within).to_string(), "1-1, 9-11, 31-40");
+    assert_eq!(set.gaps(&"3-13".parse().unwrap()).to_string(), "9-11");
+    assert!(set.gaps(&"12-30".parse().unwrap()).is_empty());
+    assert_eq!(IntervalSet::new().gaps(&within).to_string(), "1-40");
+
+    let edges: IntervalSet<u8> = intervals("0-3,250-255").into_iter().collect();
+    assert_eq!(edges.gaps(&"0-255".parse().unwrap()).to_string(), "4-249");
+}
+
+#[test]
+fn test_coverage_matches_counting() {
+    let cases = [
+        "2-4,6-8,2-3,4-5,5-7,7-9,2-8,3-7,6-6,4-6,2-6,4-8",
+        "1-1,1-1,1-1",
+        "0-255,0-0,255-255,3-9,9-12",
+        "10-20,21-30,31-31",
+    ];
+    for case in cases {
+        let intervals = intervals(case);
+        let coverage = Coverage::new(&intervals);
+
+        let mut counts = [0usize; 256];
+        for i in &intervals {
+            for s in i.start()..=i.end() {
+                counts[s as usize] += 1;
+            }
+        }
+        for s in 0..=255u8 {
+            assert_eq!(coverage.depth(s), counts[s as usize], "section {} of {}", s, case);
+            assert_eq!(coverage.more_than(1).contains_section(s), counts[s as usize] > 1);
+        }
+        let most = *counts.iter().max().unwrap();
+        let (run, depth) = coverage.busiest().unwrap();
+        assert_eq!(depth, most);
+        assert_eq!(counts.iter().position(|&c| c == most), Some(run.start() as usize));
+        assert_eq!(coverage.covered(), intervals.iter().copied().collect());
+        // Neighbouring runs always differ, or there'd be one run.
+        assert!(coverage.runs().windows(2).all(|w| !(w[0].0.adjacent(&w[1].0) && w[0].1 == w[1].1)));
+    }
+
+    assert_eq!(Coverage::<u8>::new(&[]).busiest(), None);
+}
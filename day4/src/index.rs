@@ -0,0 +1,160 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::interval::Interval;
+
+// The intervals sorted by start, as an implicit balanced tree: the middle of
+// any stretch of the list is the root of that stretch, with the halves
+// either side of it as its subtrees.  Each root also knows the furthest end
+// in its subtree, so a search can skip any subtree that finishes too early
+// to overlap.  Finding the k intervals that overlap one costs O(log n + k).
+pub struct OverlapIndex<T: Ord> {
+    // (interval, where it was in the list the index was built from)
+    sorted: Vec<(Interval<T>, usize)>,
+    // The furthest end in the subtree rooted at each position.
+    max_end: Vec<T>,
+    // Where each interval of the original list ended up in sorted.
+    slots: Vec<usize>,
+}
+
+impl<T: Ord + Copy> OverlapIndex<T> {
+    pub fn new(intervals: &[Interval<T>]) -> OverlapIndex<T> {
+        let mut sorted: Vec<(Interval<T>, usize)> = intervals.iter().copied().zip(0..).collect();
+        sorted.sort();
+        let mut max_end: Vec<T> = sorted.iter().map(|(i, _)| i.end()).collect();
+        fill_max_end(&mut max_end, 0, sorted.len());
+        let mut slots = vec![0; sorted.len()];
+        for (slot, (_, n)) in sorted.iter().enumerate() {
+            slots[*n] = slot;
+        }
+        OverlapIndex { sorted, max_end, slots }
+    }
+
+    pub fn len(&self) -> usize {
+        self.sorted.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sorted.is_empty()
+    }
+
+    // Where (in the original list) everything overlapping query is, in
+    // order.
+    pub fn overlapping(&self, query: &Interval<T>) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.search(query, 0, self.sorted.len(), &mut found);
+        found.sort();
+        found
+    }
+
+    // Everything else that overlaps the i'th interval.
+    pub fn overlapping_with(&self, i: usize) -> Vec<usize> {
+        match self.slots.get(i) {
+            Some(&slot) => {
+                let query = self.sorted[slot].0;
+                self.overlapping(&query).into_iter().filter(|&n| n != i).collect()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn search(&self, query: &Interval<T>, lo: usize, hi: usize, found: &mut Vec<usize>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if self.max_end[mid] < query.start() {
+            return;
+        }
+        self.search(query, lo, mid, found);
+        let (interval, n) = self.sorted[mid];
+        if interval.start() <= query.end() {
+            if interval.overlaps(query) {
+                found.push(n);
+            }
+            self.search(query, mid + 1, hi, found);
+        }
+    }
+
+    // How many of all the pairs of intervals overlap.  Sweeping through in
+    // order of start, each interval overlaps exactly those before it that
+    // haven't ended yet, which a heap of their ends keeps track of.
+    pub fn overlapping_pairs(&self) -> usize {
+        let mut ends = BinaryHeap::new();
+        let mut pairs = 0;
+        for (interval, _) in &self.sorted {
+            while ends.peek().is_some_and(|&Reverse(end)| end < interval.start()) {
+                ends.pop();
+            }
+            pairs += ends.len();
+            ends.push(Reverse(interval.end()));
+        }
+        pairs
+    }
+}
+
+fn fill_max_end<T: Ord + Copy>(max_end: &mut [T], lo: usize, hi: usize) -> Option<T> {
+    if lo >= hi {
+        return None;
+    }
+    let mid = lo + (hi - lo) / 2;
+    let left = fill_max_end(max_end, lo, mid);
+    let right = fill_max_end(max_end, mid + 1, hi);
+    max_end[mid] = [left, right].into_iter().flatten().fold(max_end[mid], T::max);
+    Some(max_end[mid])
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+// Plenty of intervals, some of them the same, from a little linear
+// congruential generator.
+#[cfg(test)]
+fn scattered(n: usize) -> Vec<Interval<u32>> {
+    let mut seed: u32 = 2022;
+    let mut next = move |m: u32| {
+        seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (seed >> 8) % m
+    };
+    (0..n)
+        .map(|_| {
+            let start = next(200);
+            Interval::new(start, start + next(20)).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_overlaps_match_pairwise() {
+    let intervals = scattered(300);
+    let index = OverlapIndex::new(&intervals);
+    assert_eq!(index.len(), 300);
+
+    let mut pairs = 0;
+    for (i, a) in intervals.iter().enumerate() {
+        let expected: Vec<usize> = (0..intervals.len())
+            .filter(|&j| j != i && crate::pair_overlaps_at_all(a, &intervals[j]))
+            .collect();
+        assert_eq!(index.overlapping_with(i), expected);
+        pairs += expected.len();
+    }
+    assert_eq!(index.overlapping_pairs(), pairs / 2);
+}
+
+#[test]
+fn test_overlap_index_edges() {
+    let empty = OverlapIndex::<u32>::new(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.overlapping_pairs(), 0);
+    assert_eq!(empty.overlapping_with(0), Vec::<usize>::new());
+
+    let example: Vec<Interval<u32>> = ["2-4", "6-8", "2-3", "4-5", "5-7", "7-9"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let index = OverlapIndex::new(&example);
+    assert_eq!(index.overlapping_with(0), vec![2, 3]);
+    assert_eq!(index.overlapping(&"9-20".parse().unwrap()), vec![5]);
+    assert_eq!(index.overlapping_pairs(), 6);
+}
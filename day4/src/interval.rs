@@ -0,0 +1,227 @@
+use std::fmt;
+use std::str::FromStr;
+
+// The sections from start to end, both included.  An interval always has
+// at least one section in it: start is never after end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T: Ord> {
+    start: T,
+    end: T,
+}
+
+// Section numbers, which come one after another with nothing in between.
+// That's what lets intervals be measured, and tell when two of them are
+// touching without overlapping.
+pub trait Section: Copy + Ord {
+    fn next(self) -> Option<Self>;
+    fn prev(self) -> Option<Self>;
+    // How many sections there are from start to end, both included.
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_section {
+    ($($t:ty),*) => {
+        $(
+            impl Section for $t {
+                fn next(self) -> Option<$t> {
+                    self.checked_add(1)
+                }
+
+                fn prev(self) -> Option<$t> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: $t, end: $t) -> u64 {
+                    (end as i64 - start as i64) as u64 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_section!(u8, u16, u32, i8, i16, i32);
+
+impl<T: Ord + Copy> Interval<T> {
+    pub fn new(start: T, end: T) -> Option<Interval<T>> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn single(section: T) -> Interval<T> {
+        Interval { start: section, end: section }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn contains_section(&self, section: T) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    // Is all of other inside this one?
+    pub fn contains(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl<T: Section> Interval<T> {
+    pub fn len(&self) -> u64 {
+        T::count(self.start, self.end)
+    }
+
+    // Never true, but len() looks odd without it.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    // Do the two touch end to end without sharing any sections?
+    pub fn adjacent(&self, other: &Interval<T>) -> bool {
+        self.end.next() == Some(other.start) || other.end.next() == Some(self.start)
+    }
+
+    // Both of them as one interval, if there's no gap between them to
+    // leave out.
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        (self.overlaps(other) || self.adjacent(other))
+            .then(|| Interval { start: self.start.min(other.start), end: self.end.max(other.end) })
+    }
+
+    // What's left of this one once other is taken out: nothing, one piece,
+    // or, if other is in the middle, a piece either side.
+    pub fn difference(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        let before = other.start.prev().and_then(|end| Interval::new(self.start, end));
+        let after = other.end.next().and_then(|start| Interval::new(start, self.end));
+        before.into_iter().chain(after).collect()
+    }
+}
+
+impl<T: Ord + fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// "2-4" or "2..=4" for 2 to 4, "2..5" for the same without the 5, or just
+// "2" for a single section, with or without spaces.  A range's dash is the
+// first after its first character, so a negative start still parses.
+impl<T: Section + FromStr> FromStr for Interval<T> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Interval<T>, String> {
+        let bad = || format!("{:?} is not a range like 2-4", s);
+        let section = |t: &str| t.trim().parse::<T>().map_err(|_| bad());
+        let text = s.trim();
+
+        let (start, end) = if let Some((start, end)) = text.split_once("..=") {
+            (section(start)?, section(end)?)
+        } else if let Some((start, end)) = text.split_once("..") {
+            let (start, end) = (section(start)?, section(end)?);
+            if start >= end {
+                return Err(format!("{:?} is empty", s));
+            }
+            (start, end.prev().unwrap())
+        } else if let Some((dash, _)) = text.char_indices().skip(1).find(|&(_, c)| c == '-') {
+            (section(&text[..dash])?, section(&text[dash + 1..])?)
+        } else {
+            let only = section(text)?;
+            (only, only)
+        };
+        Interval::new(start, end).ok_or_else(|| format!("{:?} ends before it starts", s))
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+// The properties are checked against plain sets of sections, for every
+// interval that fits in a small range (including its ends, where next()
+// and prev() run out).
+#[cfg(test)]
+fn small_intervals() -> Vec<Interval<u8>> {
+    let ends = [0, 1, 2, 3, 4, 5, 6, 253, 254, 255];
+    let mut all = Vec::new();
+    for &start in &ends {
+        for &end in &ends {
+            all.extend(Interval::new(start, end));
+        }
+    }
+    all
+}
+
+#[cfg(test)]
+fn sections(i: &Interval<u8>) -> std::collections::BTreeSet<u8> {
+    (i.start..=i.end).collect()
+}
+
+#[test]
+fn test_parse_interval() {
+    assert_eq!("2-4".parse::<Interval<u32>>(), Ok(Interval { start: 2, end: 4 }));
+    assert_eq!("6-6".parse::<Interval<u32>>(), Ok(Interval::single(6)));
+    assert_eq!("-3--1".parse::<Interval<i32>>(), Ok(Interval { start: -3, end: -1 }));
+    assert!("4-2".parse::<Interval<u32>>().unwrap_err().contains("ends before it starts"));
+    assert!("2-".parse::<Interval<u32>>().is_err());
+    assert!("a-b".parse::<Interval<u32>>().is_err());
+    assert!("".parse::<Interval<u32>>().is_err());
+
+    assert_eq!("7".parse::<Interval<u32>>(), Ok(Interval::single(7)));
+    assert_eq!(" 2 - 4 ".parse::<Interval<u32>>(), Ok(Interval { start: 2, end: 4 }));
+    assert_eq!("2..=4".parse::<Interval<u32>>(), Ok(Interval { start: 2, end: 4 }));
+    assert_eq!("2..5".parse::<Interval<u32>>(), Ok(Interval { start: 2, end: 4 }));
+    assert_eq!("-3..0".parse::<Interval<i32>>(), Ok(Interval { start: -3, end: -1 }));
+    assert!("5..5".parse::<Interval<u32>>().unwrap_err().contains("is empty"));
+    assert!("5..=4".parse::<Interval<u32>>().is_err());
+    assert!("2..".parse::<Interval<u32>>().is_err());
+    assert_eq!(Interval::new(2u32, 4).unwrap().to_string(), "2-4");
+}
+
+#[test]
+fn test_interval_properties() {
+    use std::collections::BTreeSet;
+
+    for a in small_intervals() {
+        let sa = sections(&a);
+        assert_eq!(a.len(), sa.len() as u64);
+        for x in 0..=255 {
+            assert_eq!(a.contains_section(x), sa.contains(&x));
+        }
+
+        for b in small_intervals() {
+            let sb = sections(&b);
+            let common: BTreeSet<u8> = sa.intersection(&sb).copied().collect();
+            let both: BTreeSet<u8> = sa.union(&sb).copied().collect();
+            let left: BTreeSet<u8> = sa.difference(&sb).copied().collect();
+
+            assert_eq!(a.contains(&b), sb.is_subset(&sa));
+            assert_eq!(a.overlaps(&b), !common.is_empty());
+            assert_eq!(a.intersection(&b).map(|i| sections(&i)).unwrap_or_default(), common);
+
+            // A union only exists when the sections form one unbroken run.
+            let unbroken = both.len() == (*both.last().unwrap() - *both.first().unwrap()) as usize + 1;
+            assert_eq!(a.union(&b).map(|i| sections(&i)), unbroken.then_some(both));
+            assert_eq!(a.adjacent(&b), unbroken && common.is_empty());
+
+            let pieces = a.difference(&b);
+            assert!(pieces.len() <= 2);
+            assert_eq!(pieces.iter().flat_map(sections).collect::<BTreeSet<u8>>(), left);
+            if let [first, second] = pieces.as_slice() {
+                assert!(first.end < second.start && !first.adjacent(second));
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use aoc_common::{AocError, Input, Solution};
+
+pub mod coverage;
+pub mod index;
+pub mod interval;
+
+use interval::Interval;
+
+fn parse_range(s: &str) -> Result<Interval<u32>, String> {
+    s.parse()
+}
+
+fn pair_includes_complete_containment(r1: &Interval<u32>, r2: &Interval<u32>) -> bool {
+    r1.contains(r2) || r2.contains(r1)
+}
+
+fn pair_overlaps_at_all(r1: &Interval<u32>, r2: &Interval<u32>) -> bool {
+    r1.overlaps(r2)
+}
+
+// The ranges from one line of the input: a pair in the puzzle, but as many
+// as the line lists.  Predicates about pairs ask about every pair of
+// ranges in the group, so with only one range there are no pairs for "any"
+// to find, and nothing to stop "all" being true.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Group {
+    pub line: usize,
+    pub ranges: Vec<Interval<u32>>,
+}
+
+impl Group {
+    pub fn parse(number: usize, line: &str) -> Result<Group, AocError> {
+        let ranges = line
+            .split(',')
+            .map(parse_range)
+            .collect::<Result<_, _>>()
+            .map_err(|e| AocError::parse(number, line, e))?;
+        Ok(Group { line: number, ranges })
+    }
+
+    fn pairs(&self) -> impl Iterator<Item = (&Interval<u32>, &Interval<u32>)> {
+        self.ranges
+            .iter()
+            .enumerate()
+            .flat_map(move |(i, a)| self.ranges[i + 1..].iter().map(move |b| (a, b)))
+    }
+
+    // Does one range of some pair cover the other?
+    pub fn any_containment(&self) -> bool {
+        self.pairs().any(|(a, b)| pair_includes_complete_containment(a, b))
+    }
+
+    // Does every pair have one covering the other, so the ranges nest?
+    pub fn all_containment(&self) -> bool {
+        self.pairs().all(|(a, b)| pair_includes_complete_containment(a, b))
+    }
+
+    pub fn any_overlap(&self) -> bool {
+        self.pairs().any(|(a, b)| pair_overlaps_at_all(a, b))
+    }
+
+    pub fn all_overlap(&self) -> bool {
+        self.pairs().all(|(a, b)| pair_overlaps_at_all(a, b))
+    }
+
+    // The sections every range in the group covers.  For intervals that's
+    // something exactly when every pair overlaps.
+    pub fn common(&self) -> Option<Interval<u32>> {
+        let (first, rest) = self.ranges.split_first()?;
+        rest.iter().try_fold(*first, |acc, r| acc.intersection(r))
+    }
+}
+
+// ------------------------------------------------------------------------------
+// TESTS
+// ------------------------------------------------------------------------------
+#[cfg(test)]
+fn group(line: &str) -> Group {
+    Group::parse(1, line).unwrap()
+}
+
+#[test]
+fn test_containment() {
+    let contained = |line| group(line).any_containment();
+    assert!(!contained("2-4,6-8"));
+    assert!(!contained("2-3,4-5"));
+    assert!(!contained("5-7,7-9"));
+    assert!(contained("2-8,3-7"));
+    assert!(contained("6-6,4-6"));
+    assert!(!contained("2-6,4-8"));
+}
+
+#[test]
+fn test_overlaps() {
+    let overlapping = |line| group(line).any_overlap();
+    assert!(!overlapping("2-4,6-8"));
+    assert!(!overlapping("2-3,4-5"));
+    assert!(overlapping("5-7,7-9"));
+    assert!(overlapping("2-8,3-7"));
+    assert!(overlapping("6-6,4-6"));
+    assert!(overlapping("2-6,4-8"));
+}
+
+#[test]
+fn test_groups() {
+    let three = group("2-8, 3-7, 4..6");
+    assert!(three.any_containment() && three.all_containment());
+    assert!(three.all_overlap());
+    assert_eq!(three.common(), Interval::new(4, 5));
+
+    let chain = group("1-3,3-5,5-7");
+    assert!(chain.any_overlap() && !chain.all_overlap());
+    assert!(!chain.any_containment());
+    assert_eq!(chain.common(), None);
+
+    let one = group("7");
+    assert!(!one.any_overlap() && !one.any_containment());
+    assert!(one.all_overlap() && one.all_containment());
+    assert_eq!(one.common(), Some(Interval::single(7)));
+
+    // For a pair, "any" and "all" are the same question.
+    for line in ["2-4,6-8", "5-7,7-9", "2-8,3-7", "2-6,4-8"] {
+        let pair = group(line);
+        assert_eq!(pair.any_overlap(), pair.all_overlap());
+        assert_eq!(pair.any_containment(), pair.all_containment());
+        assert_eq!(pair.any_overlap(), pair.common().is_some());
+    }
+}
+
+#[test]
+fn test_parse_group() {
+    assert_eq!(
+        group("2-4,6-8").ranges,
+        vec![Interval::new(2, 4).unwrap(), Interval::new(6, 8).unwrap()]
+    );
+    assert_eq!(group(" 2 - 4 ,6").ranges, vec![Interval::new(2, 4).unwrap(), Interval::single(6)]);
+    assert_eq!(group("2-4,6-8,1-1").ranges.len(), 3);
+    assert!(matches!(Group::parse(3, "2-4,"), Err(AocError::Parse { line: 3, .. })));
+    assert!(Group::parse(1, "2-4,x-8").is_err());
+    assert!(Group::parse(1, "4-2,6-8").is_err());
+    assert!(Group::parse(1, "").is_err());
+}
+// --------------------------------------------------------------------------------
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Group>;
+
+    fn parse(input: Input) -> Result<Self::Input, AocError> {
+        let lines = input.lines()?;
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, l)| Group::parse(i + 1, l))
+            .collect()
+    }
+
+    fn part1(groups: &Self::Input) -> Result<String, AocError> {
+        let fully_contained_pairs: Vec<&Group> = groups
+            .iter()
+            .filter(|g| g.any_containment())
+            .collect();
+
+        Ok(format!("{}", fully_contained_pairs.len()))
+    }
+
+    fn part2(groups: &Self::Input) -> Result<String, AocError> {
+        let overlapping_pairs: Vec<&Group> = groups
+            .iter()
+            .filter(|g| g.any_overlap())
+            .collect();
+
+        Ok(format!("{}", overlapping_pairs.len()))
+    }
+}
@@ -0,0 +1,86 @@
+// Something that can carry out moves.  The shipyard checks a move makes
+// sense and lifts the crates; the crane decides what order they're set
+// down in, which is all that separates one model from another.
+pub trait Crane {
+    fn name(&self) -> &'static str;
+    // Takes the crates lifted off a bay, bottom first, and gives back every
+    // one of them in the order they go onto the other bay, bottom first.
+    fn carry<T>(&self, crates: Vec<T>) -> Vec<T>;
+}
+
+// Picks crates up one at a time, so a stack comes out upside down.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> &'static str {
+        "CrateMover 9000"
+    }
+
+    fn carry<T>(&self, mut crates: Vec<T>) -> Vec<T> {
+        crates.reverse();
+        crates
+    }
+}
+
+// Picks up all the crates in a move at once, keeping them in order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> &'static str {
+        "CrateMover 9001"
+    }
+
+    fn carry<T>(&self, crates: Vec<T>) -> Vec<T> {
+        crates
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_cranes() {
+    let (start, moves) = crate::example();
+    assert_eq!(crate::simulate(&start, &moves, &CrateMover9000).unwrap(), "CMZ");
+    assert_eq!(crate::simulate(&start, &moves, &CrateMover9001).unwrap(), "MCD");
+
+    // Anything that sets crates down can drive the shipyard, like this one,
+    // which always fumbles the top crate onto the bottom.
+    struct Fumbler;
+    impl Crane for Fumbler {
+        fn name(&self) -> &'static str {
+            "fumbler"
+        }
+
+        fn carry<T>(&self, mut crates: Vec<T>) -> Vec<T> {
+            crates.rotate_right(1);
+            crates
+        }
+    }
+    assert_eq!(crate::simulate(&start, &moves, &Fumbler).unwrap(), "CMN");
+}
+
+#[test]
+fn test_big_moves() {
+    use crate::{Move, Shipyard};
+
+    let n = 200_000;
+    let mut sy: Shipyard<u32> = Shipyard::new(3);
+    sy.init_bay(1, (0..n).collect()).unwrap();
+    let there = Move { from: 1, to: 3, count: n as usize };
+    let back = Move { from: 3, to: 1, count: n as usize };
+
+    // Each trip with the 9000 turns the stack over; with the 9001 it
+    // doesn't.
+    for _ in 0..25 {
+        sy.execute_move(&CrateMover9000, there).unwrap();
+        sy.execute_move(&CrateMover9001, back).unwrap();
+    }
+    assert_eq!(sy.bay(1).unwrap().first(), Some(&(n - 1)));
+    assert_eq!(sy.bay(3).unwrap().len(), 0);
+    sy.execute_move(&CrateMover9000, Move { from: 1, to: 2, count: 10 }).unwrap();
+    assert_eq!(sy.bay(2).unwrap(), (0..10).collect::<Vec<u32>>());
+}
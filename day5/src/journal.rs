@@ -0,0 +1,244 @@
+use aoc_common::AocError;
+
+use crate::crane::Crane;
+use crate::{Move, Shipyard};
+
+// A move as it was carried out, with the crates it took off the bay it came
+// from, bottom first, as they were before the crane got to them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry<T> {
+    pub cmd: Move,
+    pub lifted: Vec<T>,
+}
+
+// A shipyard that remembers how it got where it is, so moves can be undone
+// and redone and any earlier state looked at again.  Step 0 is the start,
+// and step n is what the first n moves leave.
+pub struct Journal<T, C> {
+    start: Shipyard<T>,
+    current: Shipyard<T>,
+    crane: C,
+    done: Vec<Entry<T>>,
+    // Undone moves, most recently undone last.
+    undone: Vec<Entry<T>>,
+}
+
+impl<T: Clone, C: Crane> Journal<T, C> {
+    pub fn new(start: Shipyard<T>, crane: C) -> Journal<T, C> {
+        Journal {
+            current: start.clone(),
+            start,
+            crane,
+            done: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    // A journal of all the moves, one after another.
+    pub fn replay(start: Shipyard<T>, commands: &[Move], crane: C) -> Result<Journal<T, C>, AocError> {
+        let mut journal = Journal::new(start, crane);
+        for (i, cmd) in commands.iter().enumerate() {
+            journal.apply(*cmd).map_err(|e| crate::move_failed(i, cmd, e))?;
+        }
+        Ok(journal)
+    }
+
+    pub fn current(&self) -> &Shipyard<T> {
+        &self.current
+    }
+
+    pub fn crane(&self) -> &C {
+        &self.crane
+    }
+
+    // How many moves have been made (and not undone).
+    pub fn step(&self) -> usize {
+        self.done.len()
+    }
+
+    pub fn entries(&self) -> &[Entry<T>] {
+        &self.done
+    }
+
+    // Making a new move forgets anything that could have been redone.
+    pub fn apply(&mut self, cmd: Move) -> Result<(), AocError> {
+        self.record(cmd)?;
+        self.undone.clear();
+        Ok(())
+    }
+
+    fn record(&mut self, cmd: Move) -> Result<(), AocError> {
+        let bay = self.current.bay(cmd.from).unwrap_or_default();
+        let lifted = bay[bay.len().saturating_sub(cmd.count)..].to_vec();
+        self.current.execute_move(&self.crane, cmd)?;
+        self.done.push(Entry { cmd, lifted });
+        Ok(())
+    }
+
+    // Puts the crates of the last move back where they came from, and says
+    // which move that was.
+    pub fn undo(&mut self) -> Option<Move> {
+        let entry = self.done.pop()?;
+        unapply(&mut self.current, &entry);
+        let cmd = entry.cmd;
+        self.undone.push(entry);
+        Some(cmd)
+    }
+
+    // Makes the last undone move again.
+    pub fn redo(&mut self) -> Option<Move> {
+        let entry = self.undone.pop()?;
+        // It worked from here before, so it works again.
+        self.record(entry.cmd).unwrap();
+        Some(entry.cmd)
+    }
+
+    // The shipyard as it was after step moves, if there have been that
+    // many.  It's worked out from whichever end is nearer: forward from the
+    // start, or back from where things are now.
+    pub fn state_at(&self, step: usize) -> Option<Shipyard<T>> {
+        let back = self.done.len().checked_sub(step)?;
+        if step <= back {
+            let mut found = None;
+            self.walk(|s, sy| {
+                if s == step {
+                    found = Some(sy.clone());
+                }
+                s == step
+            });
+            return found;
+        }
+        let mut sy = self.current.clone();
+        for entry in self.done[step..].iter().rev() {
+            unapply(&mut sy, entry);
+        }
+        Some(sy)
+    }
+
+    // Shows visit the start, then the shipyard after each move made so far,
+    // until visit says that's enough by returning true.  Then it's the step
+    // that stopped it.
+    pub fn walk(&self, mut visit: impl FnMut(usize, &Shipyard<T>) -> bool) -> Option<usize> {
+        let mut sy = self.start.clone();
+        if visit(0, &sy) {
+            return Some(0);
+        }
+        for (i, entry) in self.done.iter().enumerate() {
+            sy.execute_move(&self.crane, entry.cmd).unwrap();
+            if visit(i + 1, &sy) {
+                return Some(i + 1);
+            }
+        }
+        None
+    }
+
+    // The first step at which test is true of the shipyard.
+    pub fn first_step(&self, test: impl Fn(&Shipyard<T>) -> bool) -> Option<usize> {
+        self.walk(|_, sy| test(sy))
+    }
+
+    // When the bay first had nothing in it.
+    pub fn first_empty(&self, bay: usize) -> Option<usize> {
+        self.first_step(|sy| sy.bay(bay).is_some_and(|b| b.is_empty()))
+    }
+
+    // When a crate matching c first sat on top of a bay, and which bay that
+    // was.
+    pub fn first_on_top(&self, c: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        let mut on = 0;
+        let step = self.walk(|_, sy| match (1..=sy.bay_count()).find(|&bay| sy.peek_bay(bay) == Some(c)) {
+            Some(bay) => {
+                on = bay;
+                true
+            }
+            None => false,
+        })?;
+        Some((step, on))
+    }
+}
+
+// Takes a move back: off the bay the crates went to, and back onto the one
+// they came from as they were.
+fn unapply<T: Clone>(sy: &mut Shipyard<T>, entry: &Entry<T>) {
+    sy.lift(entry.cmd.to, entry.cmd.count);
+    sy.set_down(entry.cmd.from, entry.lifted.iter().cloned());
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+// The puzzle's example, all moves made.
+#[cfg(test)]
+fn example<C: Crane>(crane: C) -> Journal<char, C> {
+    let (start, moves) = crate::example();
+    Journal::replay(start, &moves, crane).unwrap()
+}
+
+// Every state on the way back to the start and forward again should be
+// one the moves went through.
+#[cfg(test)]
+fn undo_redo<C: Crane>(mut journal: Journal<char, C>) {
+    let states: Vec<Shipyard<char>> = (0..=4).map(|step| journal.state_at(step).unwrap()).collect();
+    assert_eq!(states.len(), 5);
+    assert_eq!(states[4].bays, journal.current().bays);
+
+    // All the way back, checking every state on the way, then forward.
+    for state in states[..4].iter().rev() {
+        assert!(journal.undo().is_some());
+        assert_eq!(journal.current().bays, state.bays);
+    }
+    assert_eq!(journal.undo(), None);
+    for state in &states[1..] {
+        assert!(journal.redo().is_some());
+        assert_eq!(journal.current().bays, state.bays);
+    }
+    assert_eq!(journal.redo(), None);
+
+    // A new move after an undo means there's nothing to redo.
+    journal.undo();
+    journal.apply(Move { from: 3, to: 1, count: 1 }).unwrap();
+    assert_eq!(journal.redo(), None);
+    assert_eq!(journal.step(), 4);
+
+    // And a failed one isn't recorded.
+    assert!(journal.apply(Move { from: 2, to: 1, count: 9 }).is_err());
+    assert_eq!(journal.step(), 4);
+}
+
+#[test]
+fn test_undo_redo() {
+    undo_redo(example(crate::crane::CrateMover9000));
+    undo_redo(example(crate::crane::CrateMover9001));
+}
+
+#[test]
+fn test_history() {
+    let journal = example(crate::crane::CrateMover9000);
+    assert_eq!(journal.state_at(1).unwrap().bays, vec![vec!['Z', 'N', 'D'], vec!['M', 'C'], vec!['P']]);
+    assert_eq!(journal.state_at(3).unwrap().bays, vec![vec!['C', 'M'], vec![], vec!['P', 'D', 'N', 'Z']]);
+    assert!(journal.state_at(5).is_none());
+
+    // Walking forward and working back agree.
+    let mut walked = Vec::new();
+    assert_eq!(journal.walk(|_, sy| { walked.push(sy.bays.clone()); false }), None);
+    let worked_back: Vec<Vec<Vec<char>>> = (0..=4).map(|step| journal.state_at(step).unwrap().bays).collect();
+    assert_eq!(walked, worked_back);
+    assert_eq!(journal.walk(|step, _| step == 2), Some(2));
+
+    // The second move empties bay 1, and D is on top of bay 2 from the
+    // start.
+    assert_eq!(journal.first_empty(1), Some(2));
+    assert_eq!(journal.first_empty(3), None);
+    assert_eq!(journal.first_on_top(&'D'), Some((0, 2)));
+    assert_eq!(journal.first_on_top(&'Z'), Some((2, 3)));
+    assert_eq!(journal.first_on_top(&'Q'), None);
+
+    let start = journal.state_at(0).unwrap();
+    let moves = [Move { from: 2, to: 1, count: 3 }, Move { from: 2, to: 1, count: 1 }];
+    let e = Journal::replay(start, &moves, crate::crane::CrateMover9001).err().unwrap();
+    assert_eq!(e.to_string(), "move 2 (move 1 from 2 to 1): bay 2 has only 0 crates, not 1");
+}
@@ -0,0 +1,417 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use aoc_common::{AocError, Input, Solution};
+
+pub mod crane;
+pub mod journal;
+
+use crane::{Crane, CrateMover9000, CrateMover9001};
+
+// Just what the line says.  How the crates get moved is up to the crane.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub from: usize,
+    pub to: usize,
+    pub count: usize,
+}
+
+impl Move {
+    // The whole line has to be the move, give or take spaces at the ends.
+    pub fn parse(m: &str) -> Option<Move> {
+        static RE: OnceLock<Regex> = OnceLock::new();
+        let re = RE.get_or_init(|| Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap());
+        let captures = re.captures(m.trim())?;
+        Some(Move {
+            count: captures[1].parse().ok()?,
+            from: captures[2].parse().ok()?,
+            to: captures[3].parse().ok()?,
+        })
+    }
+
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+
+// Bays are numbered from 1, like the puzzle numbers them.
+#[derive(Clone, Debug)]
+pub struct Shipyard<T> {
+    bays: Vec<Vec<T>>,
+}
+
+impl<T> Shipyard<T> {
+    pub fn new(count: usize) -> Shipyard<T> {
+        let mut bays = Vec::new();
+        bays.resize_with(count, Vec::new);
+        Shipyard { bays }
+    }
+
+    pub fn bay_count(&self) -> usize {
+        self.bays.len()
+    }
+
+    // Bottom crate first.
+    pub fn bay(&self, bay: usize) -> Option<&[T]> {
+        self.bays.get(bay.checked_sub(1)?).map(Vec::as_slice)
+    }
+
+    // Stacks crates on a bay, bottom first.
+    pub fn init_bay(&mut self, bay: usize, start: Vec<T>) -> Result<(), AocError> {
+        self.check_bay(bay)?;
+        self.set_down(bay, start);
+        Ok(())
+    }
+
+    pub fn peek_bay(&self, bay: usize) -> Option<&T> {
+        self.bay(bay)?.last()
+    }
+
+    // Takes the top count crates off a bay (or all of them, if there aren't
+    // that many), bottom first.  Like set_down, it's only for bays that have
+    // been checked already.
+    fn lift(&mut self, bay: usize, count: usize) -> Vec<T> {
+        let stack = &mut self.bays[bay - 1];
+        stack.split_off(stack.len().saturating_sub(count))
+    }
+
+    // Puts crates onto a bay, bottom first.
+    fn set_down<I: IntoIterator<Item = T>>(&mut self, bay: usize, crates: I) {
+        self.bays[bay - 1].extend(crates);
+    }
+
+    // Moves that can't be done are turned down before anything moves, so
+    // a failed move leaves the shipyard as it was.
+    pub fn execute_move<C: Crane>(&mut self, crane: &C, cmd: Move) -> Result<(), AocError> {
+        self.check_move(&cmd)?;
+        let crates = crane.carry(self.lift(cmd.from, cmd.count));
+        debug_assert_eq!(crates.len(), cmd.count, "{} dropped some crates", crane.name());
+        self.set_down(cmd.to, crates);
+        Ok(())
+    }
+
+    fn check_bay(&self, bay: usize) -> Result<(), AocError> {
+        match self.bay(bay) {
+            Some(_) => Ok(()),
+            None => Err(AocError::Invalid(format!("there is no bay {} (there are {})", bay, self.bay_count()))),
+        }
+    }
+
+    fn check_move(&self, cmd: &Move) -> Result<(), AocError> {
+        let invalid = |why: String| Err(AocError::Invalid(why));
+        self.check_bay(cmd.from)?;
+        self.check_bay(cmd.to)?;
+        if cmd.from == cmd.to {
+            return invalid(format!("bay {} can't have crates moved onto itself", cmd.from));
+        }
+        let held = self.bays[cmd.from - 1].len();
+        if held < cmd.count {
+            return invalid(format!("bay {} has only {} crates, not {}", cmd.from, held, cmd.count));
+        }
+        Ok(())
+    }
+}
+
+// Drawn the way the puzzle draws it, so load_shipyard can read it back (as
+// long as each crate draws as one character and there are fewer than 100
+// bays).  Every line is padded out to the full width, like the puzzle's.
+impl<T: fmt::Display> fmt::Display for Shipyard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let height = self.bays.iter().map(Vec::len).max().unwrap_or(0);
+        for row in (0..height).rev() {
+            let cells: Vec<String> = self
+                .bays
+                .iter()
+                .map(|bay| match bay.get(row) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+        let labels: Vec<String> = (1..=self.bays.len()).map(|n| format!(" {:<2}", n)).collect();
+        write!(f, "{}", labels.join(" "))
+    }
+}
+
+//-----------------------------------------------------
+// TESTS
+//-----------------------------------------------------
+
+#[test]
+fn test_move() {
+    let m = Move::parse("move 2 from 24 to 23").unwrap();
+    assert_eq!(m.count, 2);
+    assert_eq!(m.from, 24);
+    assert_eq!(m.to, 23);
+
+    assert_eq!(Move::parse(" move 1 from 2 to 3 "), Some(Move { count: 1, from: 2, to: 3 }));
+    assert_eq!(Move::parse("xmove 1 from 2 to 3 junk"), None);
+    assert_eq!(Move::parse("move 1 from 2 to 3 junk"), None);
+    assert_eq!(Move::parse("move 1 from 2"), None);
+}
+
+#[test]
+fn test_setup() {
+    let mut sy: Shipyard<char> = Shipyard::new(3);
+    sy.init_bay(1, "ZN".chars().collect()).unwrap();
+    sy.init_bay(2, "MCD".chars().collect()).unwrap();
+    sy.init_bay(3, "P".chars().collect()).unwrap();
+    // Bays are numbered from 1, and there are only three.
+    assert!(sy.init_bay(0, vec!['X']).is_err());
+    assert!(sy.init_bay(4, vec!['X']).is_err());
+    let m1 = Move::parse("move 1 from 2 to 1").unwrap();
+    let m2 = Move::parse("move 3 from 1 to 3").unwrap();
+    let m3 = Move::parse("move 2 from 2 to 1").unwrap();
+    let m4 = Move::parse("move 1 from 1 to 2").unwrap();
+
+    sy.execute_move(&CrateMover9000, m1).unwrap();
+    sy.execute_move(&CrateMover9000, m2).unwrap();
+    sy.execute_move(&CrateMover9000, m3).unwrap();
+    sy.execute_move(&CrateMover9000, m4).unwrap();
+    
+
+    let res: Vec<char> = vec![
+        *sy.peek_bay(1).unwrap(),
+        *sy.peek_bay(2).unwrap(),
+        *sy.peek_bay(3).unwrap(),
+    ];
+
+    let key: String = res.into_iter().collect();
+    assert_eq!(key, "CMZ");
+}
+
+#[cfg(test)]
+fn drawing(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
+
+// The puzzle's example, just as the puzzle gives it.
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "    [D]    \n",
+    "[N] [C]    \n",
+    "[Z] [M] [P]\n",
+    " 1   2   3 \n",
+    "\n",
+    "move 1 from 2 to 1\n",
+    "move 3 from 1 to 3\n",
+    "move 2 from 2 to 1\n",
+    "move 1 from 1 to 2\n",
+);
+
+// The example's starting shipyard, and its moves.
+#[cfg(test)]
+fn example() -> (Shipyard<char>, Vec<Move>) {
+    Day5::parse(Input::from_reader("example", EXAMPLE.as_bytes())).unwrap()
+}
+
+#[test]
+fn test_load_shipyard() {
+    let (sy, moves) = example();
+    assert_eq!(sy.bay_count(), 3);
+    assert_eq!(moves.len(), 4);
+    assert_eq!(sy.bays, [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+
+    // Trailing spaces are optional, and empty bays are fine.
+    let sy = load_shipyard(&drawing("[A]\n[B]         [C]\n 1   2   3   4")).unwrap();
+    assert_eq!(sy.bays, [vec!['B', 'A'], vec![], vec![], vec!['C']]);
+    assert_eq!(generate_key(&sy).unwrap_err().to_string(), "no answer: bay 2 is empty");
+}
+
+#[test]
+fn test_draw_shipyard() {
+    let (sy, _) = example();
+    assert_eq!(Some(sy.to_string().as_str()), EXAMPLE.split("\n\n").next());
+
+    let mut sy = Shipyard::new(11);
+    sy.init_bay(1, vec!['A', 'B']).unwrap();
+    sy.init_bay(11, vec!['C']).unwrap();
+    let drawn = sy.to_string();
+    assert_eq!(drawn.lines().next(), Some(format!("[B]{}", " ".repeat(40)).as_str()));
+    assert!(drawn.ends_with(" 9   10  11"));
+    assert_eq!(load_shipyard(&drawing(&drawn)).unwrap().bays, sy.bays);
+
+    assert_eq!(Shipyard::<char>::new(0).to_string(), "");
+}
+
+#[test]
+fn test_bad_drawings() {
+    let line = |text| match load_shipyard(&drawing(text)) {
+        Err(AocError::Parse { line, .. }) => line,
+        other => panic!("{:?} parsed as {:?}", text, other.map(|sy| sy.bays)),
+    };
+    assert_eq!(line("[A] [B]\n 1   3 "), 2);
+    assert_eq!(line("[A] [B]\n\n"), 2);
+    assert_eq!(line("[A]\n1"), 2);
+    assert_eq!(line("[A] B  \n 1   2 "), 1);
+    assert_eq!(line("[A]  [B]\n 1   2 "), 1);
+    assert_eq!(line("[A]\n    [B]\n 1   2 "), 1);
+    assert_eq!(line("[A] [B] [C]\n 1   2 "), 1);
+    assert!(load_shipyard(&[]).is_err());
+}
+
+#[test]
+fn test_bad_moves() {
+    assert!(Move::parse("move 99999999999999999999 from 1 to 2").is_none());
+
+    let lines = vec!["move 1 from 2 to 1".to_string(), "mvoe 1 from 1 to 2".to_string()];
+    assert!(matches!(parse_moves(&lines, 1), Err(AocError::Parse { line: 2, .. })));
+    assert!(matches!(parse_moves(&lines, 11), Err(AocError::Parse { line: 12, .. })));
+
+    let (sy, _) = example();
+    let refused = |m: &str| {
+        let mut after = sy.clone();
+        let err = after.execute_move(&CrateMover9001, Move::parse(m).unwrap()).unwrap_err();
+        // Nothing moved.
+        assert_eq!(after.bays, sy.bays);
+        err.to_string()
+    };
+    assert_eq!(refused("move 1 from 4 to 1"), "there is no bay 4 (there are 3)");
+    assert_eq!(refused("move 1 from 1 to 0"), "there is no bay 0 (there are 3)");
+    assert_eq!(refused("move 4 from 2 to 1"), "bay 2 has only 3 crates, not 4");
+    assert_eq!(refused("move 1 from 2 to 2"), "bay 2 can't have crates moved onto itself");
+
+    let moves = vec![Move::parse("move 1 from 3 to 1").unwrap(), Move::parse("move 1 from 3 to 1").unwrap()];
+    assert_eq!(
+        simulate(&sy, &moves, &CrateMover9000).unwrap_err().to_string(),
+        "move 2 (move 1 from 3 to 1): bay 3 has only 0 crates, not 1"
+    );
+}
+    
+    
+// Reads the puzzle's drawing of the stacks:
+//
+//         [D]
+//     [N] [C]
+//     [Z] [M] [P]
+//      1   2   3
+//
+// The numbers along the bottom say how many bays there are, and each bay's
+// crates are drawn in the same column as its number.
+fn load_shipyard(lines: &[String]) -> Result<Shipyard<char>, AocError> {
+    let (footer, rows) = lines
+        .split_last()
+        .ok_or_else(|| AocError::Invalid("there is no drawing of the stacks".to_string()))?;
+    let bad_footer = |why: &str| AocError::parse(lines.len(), footer, why);
+
+    // Which column each bay's number (and so its crates) is in.
+    let mut columns: Vec<usize> = Vec::new();
+    let mut label = String::new();
+    for (i, c) in footer.chars().chain([' ']).enumerate() {
+        if !c.is_whitespace() {
+            label.push(c);
+            continue;
+        }
+        if label.is_empty() {
+            continue;
+        }
+        if label.parse::<usize>() != Ok(columns.len() + 1) {
+            return Err(bad_footer("expected the bays to be numbered 1, 2, 3 and so on"));
+        }
+        let start = i - label.chars().count();
+        if start == 0 {
+            return Err(bad_footer("there's no room for the first bay's crates"));
+        }
+        columns.push(start);
+        label.clear();
+    }
+    if columns.is_empty() {
+        return Err(bad_footer("there are no bays"));
+    }
+
+    let mut sy: Shipyard<char> = Shipyard::new(columns.len());
+    // Once a bay has a gap, anything drawn above it would be floating.
+    let mut topped_out = vec![false; columns.len()];
+    for (r, row) in rows.iter().enumerate().rev() {
+        let bad = |why: String| AocError::parse(r + 1, row, why);
+        let mut chars: Vec<char> = row.chars().collect();
+        for (bay, &column) in columns.iter().enumerate() {
+            let cell: Vec<char> = (column - 1..=column + 1).map(|c| chars.get(c).copied().unwrap_or(' ')).collect();
+            if cell.iter().all(|c| c.is_whitespace()) {
+                topped_out[bay] = true;
+                continue;
+            }
+            if cell[0] != '[' || cell[2] != ']' || cell[1].is_whitespace() {
+                return Err(bad(format!("expected a crate like [A] over bay {}", bay + 1)));
+            }
+            if topped_out[bay] {
+                return Err(bad(format!("the crate over bay {} has nothing under it", bay + 1)));
+            }
+            sy.init_bay(bay + 1, vec![cell[1]])?;
+            chars[column - 1..=column + 1].fill(' ');
+        }
+        if chars.iter().any(|c| !c.is_whitespace()) {
+            return Err(bad("there's something drawn that isn't over a bay".to_string()));
+        }
+    }
+    Ok(sy)
+}
+
+fn generate_key(sy: &Shipyard<char>) -> Result<String, AocError> {
+    let mut res: Vec<char> = Vec::new();
+
+    for i in 1..sy.bay_count()+1 {
+        let top = sy.peek_bay(i).ok_or_else(|| AocError::NoAnswer(format!("bay {} is empty", i)))?;
+        res.push(*top);
+    }
+    Ok(res.into_iter().collect())
+}
+
+// first_line is the line number of the first move, for errors.
+fn parse_moves(lines: &[String], first_line: usize) -> Result<Vec<Move>, AocError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| Move::parse(l).ok_or_else(|| {
+            AocError::parse(first_line + i, l, "expected \"move N from N to N\"")
+        }))
+        .collect()
+}
+
+// Says which move (numbered from 1) went wrong.
+fn move_failed(i: usize, cmd: &Move, e: AocError) -> AocError {
+    AocError::Invalid(format!("move {} ({}): {}", i + 1, cmd, e))
+}
+
+fn simulate<C: Crane>(start: &Shipyard<char>, commands: &[Move], crane: &C) -> Result<String, AocError> {
+    let mut sy: Shipyard<char> = start.clone();
+
+    for (i, cmd) in commands.iter().enumerate() {
+        sy.execute_move(crane, *cmd).map_err(|e| move_failed(i, cmd, e))?;
+    }
+
+    generate_key(&sy)
+}
+
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    // The starting shipyard, and the moves.  The moves are run by whichever
+    // crane the part calls for.
+    type Input = (Shipyard<char>, Vec<Move>);
+
+    // The drawing comes first, then a blank line, then the moves.
+    fn parse(input: Input) -> Result<Self::Input, AocError> {
+        let lines = input.lines()?;
+        let blank = lines.iter().position(|l| l.trim().is_empty()).ok_or_else(|| {
+            AocError::Invalid("expected a blank line between the drawing and the moves".to_string())
+        })?;
+        let start = load_shipyard(&lines[..blank])?;
+        Ok((start, parse_moves(&lines[blank + 1..], blank + 2)?))
+    }
+
+    fn part1((start, commands): &Self::Input) -> Result<String, AocError> {
+        simulate(start, commands, &CrateMover9000)
+    }
+
+    fn part2((start, commands): &Self::Input) -> Result<String, AocError> {
+        simulate(start, commands, &CrateMover9001)
+    }
+}
@@ -0,0 +1,76 @@
+use aoc_common::{windows, AocError, Input, Solution};
+
+const  SOP_LEN: usize = 4;
+const  SOM_LEN: usize = 14;
+
+fn uniq(cs: &[char]) -> Vec<char> {
+    let mut result: Vec<char> = Vec::new();
+    for c in cs {
+        if !result.contains(c) {
+            result.push(*c);
+        }
+    }
+    result
+}
+
+fn contains_dupes( cs: &[char]) -> bool {
+    uniq(cs) != cs
+}
+
+// How many chars have to be read before the last len of them are all
+// different, if that ever happens.
+fn detect_marker(cs: &[char], len: usize) -> Option<usize> {
+    windows(cs, len)
+        .position(|w| !contains_dupes(w))
+        .map(|i| i + len)
+}
+
+fn detect_sop(cs: Vec<char>) -> Option<usize> {
+    detect_marker(&cs, SOP_LEN)
+}
+
+fn detect_som(cs: Vec<char>) -> Option<usize> {
+    detect_marker(&cs, SOM_LEN)
+}
+
+
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<char>;
+
+    fn parse(input: Input) -> Result<Vec<char>, AocError> {
+        Ok(input.chars()?)
+    }
+
+    fn part1(signal: &Vec<char>) -> Result<String, AocError> {
+        let sop = detect_sop(signal.clone())
+            .ok_or(AocError::NoAnswer("no start-of-packet marker".to_string()))?;
+        Ok(format!("{:?}", sop))
+    }
+
+    fn part2(signal: &Vec<char>) -> Result<String, AocError> {
+        let som = detect_som(signal.clone())
+            .ok_or(AocError::NoAnswer("no start-of-message marker".to_string()))?;
+        Ok(format!("{:?}", som))
+    }
+}
+
+// --------------------------------------------------------------------------
+// TESTS
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_detect_markers() {
+    let signal: Vec<char> = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".chars().collect();
+    assert_eq!(detect_sop(signal.clone()), Some(7));
+    assert_eq!(detect_som(signal), Some(19));
+
+    // The marker can be the very last thing in the signal...
+    assert_eq!(detect_sop("aaabcd".chars().collect()), Some(6));
+
+    // ...or not be there at all.
+    assert_eq!(detect_sop("abab".chars().collect()), None);
+    assert_eq!(detect_som("ab".chars().collect()), None);
+}